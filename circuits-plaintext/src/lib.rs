@@ -0,0 +1,1146 @@
+//! Plaintext reference implementation of the `encrypted-ixs` circuits
+//!
+//! Same structs, same logic, no `arcis`: every `Enc<_, T>` becomes a plain `T`
+//! and owners/keys are dropped. Used by the property tests in `tests/` to check
+//! circuit invariants on a plain Linux box.
+//!
+//! Keep this in lockstep with `circuits`; `tests/lockstep.rs` fails when an
+//! instruction, struct field or constant there has no match here. MPC integer
+//! arithmetic wraps and both branches of every `if` are evaluated, so
+//! intermediate values use `wrapping_*` ops here to match.
+
+/// `PrivateTransferOutput::error_code`: transfer applied
+pub const TRANSFER_OK: u8 = 0;
+/// `PrivateTransferOutput::error_code`: balance below
+/// `amount + fee + min_balance`
+pub const TRANSFER_INSUFFICIENT_FUNDS: u8 = 1;
+/// `PrivateTransferOutput::error_code`: `amount + fee + min_balance` or the
+/// recipient's incoming credit exceeds `u64::MAX`
+pub const TRANSFER_OVERFLOW: u8 = 2;
+/// `PrivateTransferOutput::error_code`: encrypted recipient does not own
+/// the credited balance account
+pub const TRANSFER_RECIPIENT_MISMATCH: u8 = 3;
+/// `PrivateTransferOutput::error_code`: encrypted sequence does not match
+/// the sender balance, the input was built against an outdated balance
+pub const TRANSFER_STALE_SEQUENCE: u8 = 4;
+
+/// Fee rates are in basis points of the transferred amount
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Mirror of `circuits::create_balance`, returns `(balance, incoming)`
+pub fn create_balance() -> (u64, u64) {
+    (0, 0)
+}
+
+/// Mirror of `circuits::deposit`
+pub fn deposit(balance: u64, amount: u64) -> u64 {
+    balance.wrapping_add(amount)
+}
+
+/// Mirror of `circuits::withdraw`, returns `(new_balance, released)`
+pub fn withdraw(balance: u64, amount: u64) -> (u64, u64) {
+    let has_funds = balance >= amount;
+    if has_funds {
+        (balance.wrapping_sub(amount), amount)
+    } else {
+        (balance, 0)
+    }
+}
+
+/// Mirror of `circuits::IncomingClaim`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncomingClaim {
+    pub claimed: u64,
+    pub new_balance: u64,
+}
+
+/// Mirror of `circuits::claim_incoming`, returns
+/// `(new_balance, new_incoming, claim)`
+pub fn claim_incoming(balance: u64, incoming: u64) -> (u64, u64, IncomingClaim) {
+    let overflows = incoming > u64::MAX - balance;
+    let (new_balance, new_incoming, claimed) = if overflows {
+        (balance, incoming, 0)
+    } else {
+        (balance.wrapping_add(incoming), 0, incoming)
+    };
+    (
+        new_balance,
+        new_incoming,
+        IncomingClaim {
+            claimed,
+            new_balance,
+        },
+    )
+}
+
+/// Mirror of `circuits::PrivateTransferInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrivateTransferInput {
+    pub amount: u64,
+    pub min_balance: u64,
+    pub recipient_lo: u128,
+    pub recipient_hi: u128,
+    pub sequence: u64,
+}
+
+/// Mirror of `circuits::PrivateTransferOutput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrivateTransferOutput {
+    pub is_valid: bool,
+    pub new_sender_balance: u64,
+    pub error_code: u8,
+    pub fee: u64,
+}
+
+/// Mirror of `circuits::TransferReceipt`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferReceipt {
+    pub amount: u64,
+    pub new_incoming: u64,
+}
+
+/// Mirror of `circuits::TransferDebit`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferDebit {
+    pub amount: u64,
+    pub fee: u64,
+    pub new_sender_balance: u64,
+}
+
+/// Result of [`private_transfer`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrivateTransferResult {
+    pub output: PrivateTransferOutput,
+    pub receipt: TransferReceipt,
+    pub new_sender_balance: u64,
+    pub new_incoming: u64,
+    pub fee: u64,
+    pub debit_view: TransferDebit,
+    pub credit_view: TransferReceipt,
+}
+
+/// Mirror of `circuits::private_transfer`
+#[allow(clippy::too_many_arguments)]
+pub fn private_transfer(
+    input: PrivateTransferInput,
+    sender_balance: u64,
+    sender_sequence: u64,
+    recipient_incoming: u64,
+    recipient_owner_lo: u128,
+    recipient_owner_hi: u128,
+    fee_bps: u16,
+) -> PrivateTransferResult {
+    let fee = ((input.amount as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
+
+    let debit_overflows = fee > u64::MAX - input.amount;
+    let debit = input.amount.wrapping_add(fee);
+    let required_overflows = debit_overflows || input.min_balance > u64::MAX.wrapping_sub(debit);
+    let required = debit.wrapping_add(input.min_balance);
+    let has_funds = !required_overflows && sender_balance >= required;
+
+    let recipient_matches =
+        input.recipient_lo == recipient_owner_lo && input.recipient_hi == recipient_owner_hi;
+
+    let credit_overflows = input.amount > u64::MAX - recipient_incoming;
+
+    let sequence_matches = input.sequence == sender_sequence;
+
+    let error_code = if !sequence_matches {
+        TRANSFER_STALE_SEQUENCE
+    } else if required_overflows || credit_overflows {
+        TRANSFER_OVERFLOW
+    } else if !has_funds {
+        TRANSFER_INSUFFICIENT_FUNDS
+    } else if !recipient_matches {
+        TRANSFER_RECIPIENT_MISMATCH
+    } else {
+        TRANSFER_OK
+    };
+    let is_valid = error_code == TRANSFER_OK;
+
+    let (new_sender_balance, new_incoming, credited, charged) = if is_valid {
+        (
+            sender_balance.wrapping_sub(debit),
+            recipient_incoming.wrapping_add(input.amount),
+            input.amount,
+            fee,
+        )
+    } else {
+        (sender_balance, recipient_incoming, 0, 0)
+    };
+
+    PrivateTransferResult {
+        output: PrivateTransferOutput {
+            is_valid,
+            new_sender_balance,
+            error_code,
+            fee: charged,
+        },
+        receipt: TransferReceipt {
+            amount: credited,
+            new_incoming,
+        },
+        new_sender_balance,
+        new_incoming,
+        fee: charged,
+        debit_view: TransferDebit {
+            amount: credited,
+            fee: charged,
+            new_sender_balance,
+        },
+        credit_view: TransferReceipt {
+            amount: credited,
+            new_incoming,
+        },
+    }
+}
+
+/// Mirror of `circuits::BATCH_SIZE`
+pub const BATCH_SIZE: usize = 4;
+
+/// Mirror of `circuits::BatchTransferInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchTransferInput {
+    pub amounts: [u64; BATCH_SIZE],
+    pub recipients_lo: [u128; BATCH_SIZE],
+    pub recipients_hi: [u128; BATCH_SIZE],
+    pub min_balance: u64,
+    pub sequence: u64,
+}
+
+/// Mirror of `circuits::BatchTransferDebit`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchTransferDebit {
+    pub amounts: [u64; BATCH_SIZE],
+    pub fee: u64,
+    pub new_sender_balance: u64,
+}
+
+/// Result of [`batch_transfer`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchTransferResult {
+    pub output: PrivateTransferOutput,
+    pub new_sender_balance: u64,
+    pub new_incoming: [u64; BATCH_SIZE],
+    pub fee: u64,
+    pub debit_view: BatchTransferDebit,
+    pub credit_views: [TransferReceipt; BATCH_SIZE],
+}
+
+/// Mirror of `circuits::batch_transfer`
+#[allow(clippy::too_many_arguments)]
+pub fn batch_transfer(
+    input: BatchTransferInput,
+    sender_balance: u64,
+    sender_sequence: u64,
+    recipient_incoming: [u64; BATCH_SIZE],
+    recipient_owners_lo: [u128; BATCH_SIZE],
+    recipient_owners_hi: [u128; BATCH_SIZE],
+    fee_bps: u16,
+) -> BatchTransferResult {
+    let mut total = 0u64;
+    let mut fee = 0u64;
+    let mut sum_overflows = false;
+    let mut credit_overflows = false;
+    let mut recipients_match = true;
+    for i in 0..BATCH_SIZE {
+        let amount = input.amounts[i];
+        let incoming = recipient_incoming[i];
+
+        let slot_fee = ((amount as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
+        sum_overflows = sum_overflows || amount > u64::MAX - total || slot_fee > u64::MAX - fee;
+        total = total.wrapping_add(amount);
+        fee = fee.wrapping_add(slot_fee);
+
+        credit_overflows = credit_overflows || amount > u64::MAX - incoming;
+
+        let slot_matches = input.recipients_lo[i] == recipient_owners_lo[i]
+            && input.recipients_hi[i] == recipient_owners_hi[i];
+        recipients_match = recipients_match && (amount == 0 || slot_matches);
+    }
+    let debit_overflows = sum_overflows || fee > u64::MAX - total;
+    let debit = total.wrapping_add(fee);
+    let required_overflows = debit_overflows || input.min_balance > u64::MAX.wrapping_sub(debit);
+    let required = debit.wrapping_add(input.min_balance);
+    let has_funds = !required_overflows && sender_balance >= required;
+
+    let sequence_matches = input.sequence == sender_sequence;
+
+    let error_code = if !sequence_matches {
+        TRANSFER_STALE_SEQUENCE
+    } else if required_overflows || credit_overflows {
+        TRANSFER_OVERFLOW
+    } else if !has_funds {
+        TRANSFER_INSUFFICIENT_FUNDS
+    } else if !recipients_match {
+        TRANSFER_RECIPIENT_MISMATCH
+    } else {
+        TRANSFER_OK
+    };
+    let is_valid = error_code == TRANSFER_OK;
+
+    let mut new_incoming = [0u64; BATCH_SIZE];
+    let mut credited = [0u64; BATCH_SIZE];
+    for i in 0..BATCH_SIZE {
+        new_incoming[i] = if is_valid {
+            recipient_incoming[i].wrapping_add(input.amounts[i])
+        } else {
+            recipient_incoming[i]
+        };
+        credited[i] = if is_valid { input.amounts[i] } else { 0 };
+    }
+    let (new_sender_balance, charged) = if is_valid {
+        (sender_balance.wrapping_sub(debit), fee)
+    } else {
+        (sender_balance, 0)
+    };
+
+    BatchTransferResult {
+        output: PrivateTransferOutput {
+            is_valid,
+            new_sender_balance,
+            error_code,
+            fee: charged,
+        },
+        new_sender_balance,
+        new_incoming,
+        fee: charged,
+        debit_view: BatchTransferDebit {
+            amounts: credited,
+            fee: charged,
+            new_sender_balance,
+        },
+        credit_views: std::array::from_fn(|i| TransferReceipt {
+            amount: credited[i],
+            new_incoming: new_incoming[i],
+        }),
+    }
+}
+
+/// Mirror of `circuits::sweep_fee`, returns `(new_balance, swept)`
+pub fn sweep_fee(balance: u64, fee: u64) -> (u64, bool) {
+    let swept = fee <= u64::MAX - balance;
+    let new_balance = if swept {
+        balance.wrapping_add(fee)
+    } else {
+        balance
+    };
+    (new_balance, swept)
+}
+
+/// Mirror of `circuits::BalanceCheckInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BalanceCheckInput {
+    pub minimum: u64,
+}
+
+/// Mirror of `circuits::check_balance`
+pub fn check_balance(input: BalanceCheckInput, balance: u64) -> bool {
+    balance >= input.minimum
+}
+
+/// Mirror of `circuits::ConfidentialSwapInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfidentialSwapInput {
+    pub input_amount: u64,
+    pub min_output: u64,
+    pub actual_output: u64,
+}
+
+/// Mirror of `circuits::ConfidentialSwapOutput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfidentialSwapOutput {
+    pub is_valid: bool,
+    pub new_input_balance: u64,
+    pub slippage_ok: bool,
+    pub new_output_balance: u64,
+}
+
+/// Mirror of `circuits::validate_swap`
+pub fn validate_swap(
+    input: ConfidentialSwapInput,
+    input_balance: u64,
+    output_balance: u64,
+) -> ConfidentialSwapOutput {
+    let has_balance = input_balance >= input.input_amount;
+    let slippage_ok = input.actual_output >= input.min_output;
+    let credit_overflows = input.actual_output > u64::MAX - output_balance;
+    let is_valid = has_balance && slippage_ok && !credit_overflows;
+
+    let (new_input_balance, new_output_balance) = if is_valid {
+        (
+            input_balance.wrapping_sub(input.input_amount),
+            output_balance.wrapping_add(input.actual_output),
+        )
+    } else {
+        (input_balance, output_balance)
+    };
+
+    ConfidentialSwapOutput {
+        is_valid,
+        new_input_balance,
+        slippage_ok,
+        new_output_balance,
+    }
+}
+
+/// Mirror of `circuits::PRICE_SCALE`
+pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+/// Mirror of `circuits::OracleSwapInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleSwapInput {
+    pub input_amount: u64,
+    pub min_output: u64,
+}
+
+/// Mirror of `circuits::OracleSwapOutput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleSwapOutput {
+    pub is_valid: bool,
+    pub slippage_ok: bool,
+    pub expected_output: u64,
+}
+
+/// Mirror of `circuits::validate_oracle_swap`
+pub fn validate_oracle_swap(
+    input: OracleSwapInput,
+    input_balance: u64,
+    output_balance: u64,
+    price: u64,
+) -> OracleSwapOutput {
+    let expected_wide = (input.input_amount as u128) * (price as u128) / PRICE_SCALE;
+    let output_overflows = expected_wide > u64::MAX as u128;
+    let expected_output = if output_overflows {
+        u64::MAX
+    } else {
+        expected_wide as u64
+    };
+
+    let has_balance = input_balance >= input.input_amount;
+    let slippage_ok = expected_output >= input.min_output;
+    let credit_overflows = output_overflows || expected_output > u64::MAX - output_balance;
+    let is_valid = has_balance && slippage_ok && !credit_overflows;
+
+    OracleSwapOutput {
+        is_valid,
+        slippage_ok,
+        expected_output,
+    }
+}
+
+/// Mirror of `circuits::PoolReserves`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolReserves {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
+
+/// Mirror of `circuits::init_pool`
+pub fn init_pool() -> PoolReserves {
+    PoolReserves {
+        reserve_a: 0,
+        reserve_b: 0,
+    }
+}
+
+/// Mirror of `circuits::LiquidityInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LiquidityInput {
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
+
+/// Result of [`add_liquidity`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityResult {
+    pub is_valid: bool,
+    pub new_provider_a: u64,
+    pub new_provider_b: u64,
+    pub new_reserves: PoolReserves,
+}
+
+/// Mirror of `circuits::add_liquidity`
+pub fn add_liquidity(
+    input: LiquidityInput,
+    provider_a: u64,
+    provider_b: u64,
+    reserves: PoolReserves,
+) -> AddLiquidityResult {
+    let has_funds = provider_a >= input.amount_a && provider_b >= input.amount_b;
+    let reserves_overflow = input.amount_a > u64::MAX - reserves.reserve_a
+        || input.amount_b > u64::MAX - reserves.reserve_b;
+    let is_valid = has_funds && !reserves_overflow;
+
+    let (new_provider_a, new_provider_b, new_reserves) = if is_valid {
+        (
+            provider_a.wrapping_sub(input.amount_a),
+            provider_b.wrapping_sub(input.amount_b),
+            PoolReserves {
+                reserve_a: reserves.reserve_a.wrapping_add(input.amount_a),
+                reserve_b: reserves.reserve_b.wrapping_add(input.amount_b),
+            },
+        )
+    } else {
+        (provider_a, provider_b, reserves)
+    };
+
+    AddLiquidityResult {
+        is_valid,
+        new_provider_a,
+        new_provider_b,
+        new_reserves,
+    }
+}
+
+/// Mirror of `circuits::remove_liquidity`
+///
+/// Returns the same shape as [`add_liquidity`].
+pub fn remove_liquidity(
+    input: LiquidityInput,
+    provider_a: u64,
+    provider_b: u64,
+    reserves: PoolReserves,
+) -> AddLiquidityResult {
+    let has_reserves = reserves.reserve_a >= input.amount_a && reserves.reserve_b >= input.amount_b;
+    let provider_overflows =
+        input.amount_a > u64::MAX - provider_a || input.amount_b > u64::MAX - provider_b;
+    let is_valid = has_reserves && !provider_overflows;
+
+    let (new_provider_a, new_provider_b, new_reserves) = if is_valid {
+        (
+            provider_a.wrapping_add(input.amount_a),
+            provider_b.wrapping_add(input.amount_b),
+            PoolReserves {
+                reserve_a: reserves.reserve_a.wrapping_sub(input.amount_a),
+                reserve_b: reserves.reserve_b.wrapping_sub(input.amount_b),
+            },
+        )
+    } else {
+        (provider_a, provider_b, reserves)
+    };
+
+    AddLiquidityResult {
+        is_valid,
+        new_provider_a,
+        new_provider_b,
+        new_reserves,
+    }
+}
+
+/// Mirror of `circuits::CpmmSwapInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpmmSwapInput {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+    pub a_to_b: bool,
+}
+
+/// Mirror of `circuits::CpmmSwapOutput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpmmSwapOutput {
+    pub is_valid: bool,
+    pub amount_out: u64,
+    pub fee: u64,
+}
+
+/// Result of [`swap_cpmm`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpmmSwapResult {
+    pub output: CpmmSwapOutput,
+    pub new_trader_a: u64,
+    pub new_trader_b: u64,
+    pub new_reserves: PoolReserves,
+}
+
+/// Mirror of `circuits::swap_cpmm`
+pub fn swap_cpmm(
+    input: CpmmSwapInput,
+    trader_a: u64,
+    trader_b: u64,
+    reserves: PoolReserves,
+    fee_bps: u16,
+) -> CpmmSwapResult {
+    let (balance_in, balance_out, reserve_in, reserve_out) = if input.a_to_b {
+        (trader_a, trader_b, reserves.reserve_a, reserves.reserve_b)
+    } else {
+        (trader_b, trader_a, reserves.reserve_b, reserves.reserve_a)
+    };
+
+    let fee = ((input.amount_in as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
+    let net_in = input.amount_in.wrapping_sub(fee) as u128;
+    let numerator = (reserve_out as u128) * net_in;
+    let denominator = (reserve_in as u128) + net_in;
+    let safe_denominator = if denominator == 0 { 1 } else { denominator };
+    let amount_out = (numerator / safe_denominator) as u64;
+
+    let has_funds = balance_in >= input.amount_in;
+    let pool_empty = reserve_in == 0 || reserve_out == 0;
+    let overflows = input.amount_in > u64::MAX - reserve_in || amount_out > u64::MAX - balance_out;
+    let slippage_ok = amount_out > 0 && amount_out >= input.min_amount_out;
+    let is_valid = has_funds && !pool_empty && !overflows && slippage_ok;
+
+    let (new_balance_in, new_balance_out, new_reserve_in, new_reserve_out, paid, charged) =
+        if is_valid {
+            (
+                balance_in.wrapping_sub(input.amount_in),
+                balance_out.wrapping_add(amount_out),
+                reserve_in.wrapping_add(input.amount_in),
+                reserve_out.wrapping_sub(amount_out),
+                amount_out,
+                fee,
+            )
+        } else {
+            (balance_in, balance_out, reserve_in, reserve_out, 0, 0)
+        };
+
+    let (new_trader_a, new_trader_b, new_reserve_a, new_reserve_b) = if input.a_to_b {
+        (
+            new_balance_in,
+            new_balance_out,
+            new_reserve_in,
+            new_reserve_out,
+        )
+    } else {
+        (
+            new_balance_out,
+            new_balance_in,
+            new_reserve_out,
+            new_reserve_in,
+        )
+    };
+
+    CpmmSwapResult {
+        output: CpmmSwapOutput {
+            is_valid,
+            amount_out: paid,
+            fee: charged,
+        },
+        new_trader_a,
+        new_trader_b,
+        new_reserves: PoolReserves {
+            reserve_a: new_reserve_a,
+            reserve_b: new_reserve_b,
+        },
+    }
+}
+
+/// Mirror of `circuits::OrderInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderInput {
+    pub is_buy: bool,
+    pub price: u64,
+    pub size: u64,
+}
+
+/// Mirror of `circuits::OrderState`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderState {
+    pub is_buy: bool,
+    pub price: u64,
+    pub remaining: u64,
+    pub escrow: u64,
+    pub proceeds: u64,
+}
+
+/// Mirror of `circuits::OrderFill`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderFill {
+    pub filled: u64,
+    pub remaining: u64,
+    pub proceeds: u64,
+}
+
+/// Result of [`place_order`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlaceOrderResult {
+    pub is_valid: bool,
+    pub order: OrderState,
+    pub new_base_balance: u64,
+    pub new_quote_balance: u64,
+}
+
+/// Result of [`match_orders`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatchOrdersResult {
+    pub new_order_a: OrderState,
+    pub new_order_b: OrderState,
+    pub fill_a: OrderFill,
+    pub fill_b: OrderFill,
+    pub matched: bool,
+}
+
+/// Result of [`settle_order`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SettleOrderResult {
+    pub new_order: OrderState,
+    pub new_base_balance: u64,
+    pub new_quote_balance: u64,
+    pub settled: bool,
+}
+
+/// Mirror of `circuits::place_order`
+pub fn place_order(input: OrderInput, base_balance: u64, quote_balance: u64) -> PlaceOrderResult {
+    let notional_wide = (input.size as u128) * (input.price as u128) / PRICE_SCALE;
+    let notional_overflows = input.is_buy && notional_wide > u64::MAX as u128;
+    let escrow = if input.is_buy {
+        notional_wide as u64
+    } else {
+        input.size
+    };
+    let available = if input.is_buy {
+        quote_balance
+    } else {
+        base_balance
+    };
+    let is_valid = available >= escrow && !notional_overflows;
+
+    let (remaining, held) = if is_valid {
+        (input.size, escrow)
+    } else {
+        (0, 0)
+    };
+    let (new_base_balance, new_quote_balance) = if input.is_buy {
+        (base_balance, quote_balance.wrapping_sub(held))
+    } else {
+        (base_balance.wrapping_sub(held), quote_balance)
+    };
+
+    PlaceOrderResult {
+        is_valid,
+        order: OrderState {
+            is_buy: input.is_buy,
+            price: input.price,
+            remaining,
+            escrow: held,
+            proceeds: 0,
+        },
+        new_base_balance,
+        new_quote_balance,
+    }
+}
+
+/// Mirror of `circuits::match_orders`
+pub fn match_orders(order_a: OrderState, order_b: OrderState) -> MatchOrdersResult {
+    let (buy, sell) = if order_a.is_buy {
+        (order_a, order_b)
+    } else {
+        (order_b, order_a)
+    };
+    let smaller = if order_a.remaining < order_b.remaining {
+        order_a.remaining
+    } else {
+        order_b.remaining
+    };
+    let cost_wide = (smaller as u128) * (sell.price as u128) / PRICE_SCALE;
+    let cost = if cost_wide > u64::MAX as u128 {
+        u64::MAX
+    } else {
+        cost_wide as u64
+    };
+
+    let opposite_sides = order_a.is_buy != order_b.is_buy;
+    let has_size = order_a.remaining > 0 && order_b.remaining > 0;
+    let covered = cost <= buy.escrow && smaller <= sell.escrow;
+    let proceeds_overflow = cost > u64::MAX - sell.proceeds;
+    let matched =
+        opposite_sides && has_size && buy.price >= sell.price && covered && !proceeds_overflow;
+
+    let (filled, paid) = if matched { (smaller, cost) } else { (0, 0) };
+
+    let new_buy = OrderState {
+        is_buy: buy.is_buy,
+        price: buy.price,
+        remaining: buy.remaining.wrapping_sub(filled),
+        escrow: buy.escrow.wrapping_sub(paid),
+        proceeds: buy.proceeds.wrapping_add(filled),
+    };
+    let new_sell = OrderState {
+        is_buy: sell.is_buy,
+        price: sell.price,
+        remaining: sell.remaining.wrapping_sub(filled),
+        escrow: sell.escrow.wrapping_sub(filled),
+        proceeds: sell.proceeds.wrapping_add(paid),
+    };
+    let (new_order_a, new_order_b) = if !matched {
+        (order_a, order_b)
+    } else if order_a.is_buy {
+        (new_buy, new_sell)
+    } else {
+        (new_sell, new_buy)
+    };
+
+    MatchOrdersResult {
+        new_order_a,
+        new_order_b,
+        fill_a: OrderFill {
+            filled,
+            remaining: new_order_a.remaining,
+            proceeds: new_order_a.proceeds,
+        },
+        fill_b: OrderFill {
+            filled,
+            remaining: new_order_b.remaining,
+            proceeds: new_order_b.proceeds,
+        },
+        matched,
+    }
+}
+
+/// Mirror of `circuits::settle_order`
+pub fn settle_order(
+    order: OrderState,
+    base_balance: u64,
+    quote_balance: u64,
+    close: bool,
+) -> SettleOrderResult {
+    let refund = if close { order.escrow } else { 0 };
+    let (base_credit, quote_credit) = if order.is_buy {
+        (order.proceeds, refund)
+    } else {
+        (refund, order.proceeds)
+    };
+    let settled =
+        base_credit <= u64::MAX - base_balance && quote_credit <= u64::MAX - quote_balance;
+
+    let (new_order, new_base_balance, new_quote_balance) = if settled {
+        (
+            OrderState {
+                is_buy: order.is_buy,
+                price: order.price,
+                remaining: if close { 0 } else { order.remaining },
+                escrow: order.escrow.wrapping_sub(refund),
+                proceeds: 0,
+            },
+            base_balance.wrapping_add(base_credit),
+            quote_balance.wrapping_add(quote_credit),
+        )
+    } else {
+        (order, base_balance, quote_balance)
+    };
+
+    SettleOrderResult {
+        new_order,
+        new_base_balance,
+        new_quote_balance,
+        settled,
+    }
+}
+
+/// Mirror of `circuits::AUCTION_SIZE`
+pub const AUCTION_SIZE: usize = 4;
+
+/// Mirror of `circuits::AuctionFill`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuctionFill {
+    pub filled: u64,
+    pub clearing_price: u64,
+    pub remaining: u64,
+    pub proceeds: u64,
+}
+
+/// Result of [`batch_auction`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchAuctionResult {
+    pub new_orders: [OrderState; AUCTION_SIZE],
+    pub fills: [AuctionFill; AUCTION_SIZE],
+    pub cleared: bool,
+}
+
+/// Mirror of `circuits::batch_auction`
+pub fn batch_auction(orders: [OrderState; AUCTION_SIZE]) -> BatchAuctionResult {
+    let mut clearing_price = 0u64;
+    let mut volume = 0u128;
+    for candidate_order in orders {
+        let candidate = candidate_order.price;
+        let mut demand = 0u128;
+        let mut supply = 0u128;
+        for order in orders {
+            let buys = order.is_buy && order.price >= candidate;
+            let sells = !order.is_buy && order.price <= candidate;
+            demand += if buys { order.remaining as u128 } else { 0 };
+            supply += if sells { order.remaining as u128 } else { 0 };
+        }
+        let matched = if demand < supply { demand } else { supply };
+
+        let better = matched > volume || (matched == volume && candidate < clearing_price);
+        clearing_price = if better { candidate } else { clearing_price };
+        volume = if better { matched } else { volume };
+    }
+
+    let mut buys_left = volume;
+    let mut sells_left = volume;
+    let mut filled = [0u64; AUCTION_SIZE];
+    for (i, order) in orders.iter().enumerate() {
+        let eligible = if order.is_buy {
+            order.price >= clearing_price
+        } else {
+            order.price <= clearing_price
+        };
+        let left = if order.is_buy { buys_left } else { sells_left };
+        let wanted = if eligible { order.remaining as u128 } else { 0 };
+        let fill = if wanted < left { wanted } else { left };
+        (buys_left, sells_left) = if order.is_buy {
+            (buys_left.wrapping_sub(fill), sells_left)
+        } else {
+            (buys_left, sells_left.wrapping_sub(fill))
+        };
+        filled[i] = fill as u64;
+    }
+
+    let mut paid = [0u128; AUCTION_SIZE];
+    let mut total_paid = 0u128;
+    let mut covered = true;
+    for (i, order) in orders.iter().enumerate() {
+        let cost = (filled[i] as u128) * (clearing_price as u128) / PRICE_SCALE;
+        paid[i] = if order.is_buy { cost } else { 0 };
+        total_paid = total_paid.wrapping_add(paid[i]);
+        let spent = if order.is_buy {
+            cost
+        } else {
+            filled[i] as u128
+        };
+        covered = covered && spent <= order.escrow as u128;
+    }
+
+    let mut later_sell = false;
+    let mut last_sell = [false; AUCTION_SIZE];
+    for i in (0..AUCTION_SIZE).rev() {
+        let sells = !orders[i].is_buy && filled[i] > 0;
+        last_sell[i] = sells && !later_sell;
+        later_sell = later_sell || sells;
+    }
+
+    let mut paid_left = total_paid;
+    let mut received = [0u128; AUCTION_SIZE];
+    for (i, order) in orders.iter().enumerate() {
+        let share = (filled[i] as u128) * (clearing_price as u128) / PRICE_SCALE;
+        let capped = if share < paid_left { share } else { paid_left };
+        let take = if last_sell[i] { paid_left } else { capped };
+        received[i] = if order.is_buy {
+            filled[i] as u128
+        } else {
+            take
+        };
+        paid_left = paid_left.wrapping_sub(if order.is_buy { 0 } else { take });
+    }
+
+    let mut fits = true;
+    let mut new_proceeds_wide = [0u128; AUCTION_SIZE];
+    for (i, order) in orders.iter().enumerate() {
+        new_proceeds_wide[i] = (order.proceeds as u128).wrapping_add(received[i]);
+        fits = fits && new_proceeds_wide[i] <= u64::MAX as u128;
+    }
+    let settles = covered && fits;
+    let cleared = volume > 0 && settles;
+
+    let fill_price = if cleared { clearing_price } else { 0 };
+    let mut new_orders = orders;
+    let mut fills = [AuctionFill {
+        filled: 0,
+        clearing_price: 0,
+        remaining: 0,
+        proceeds: 0,
+    }; AUCTION_SIZE];
+    for (i, order) in orders.iter().enumerate() {
+        if settles {
+            let spent = if order.is_buy {
+                paid[i] as u64
+            } else {
+                filled[i]
+            };
+            new_orders[i].remaining = order.remaining.wrapping_sub(filled[i]);
+            new_orders[i].escrow = order.escrow.wrapping_sub(spent);
+            new_orders[i].proceeds = new_proceeds_wide[i] as u64;
+        }
+        fills[i] = AuctionFill {
+            filled: if settles { filled[i] } else { 0 },
+            clearing_price: fill_price,
+            remaining: new_orders[i].remaining,
+            proceeds: new_orders[i].proceeds,
+        };
+    }
+
+    BatchAuctionResult {
+        new_orders,
+        fills,
+        cleared,
+    }
+}
+
+/// Mirror of `circuits::NO_BID`
+pub const NO_BID: u8 = u8::MAX;
+
+/// Mirror of `circuits::SealedBids`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SealedBids {
+    pub highest: u64,
+    pub second: u64,
+    pub winner: u8,
+}
+
+/// Result of [`init_auction`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitAuctionResult {
+    pub bids: SealedBids,
+    pub new_lot_balance: u64,
+    pub escrowed: bool,
+}
+
+/// Mirror of `circuits::init_auction`
+pub fn init_auction(lot_balance: u64, lot_amount: u64) -> InitAuctionResult {
+    let escrowed = lot_balance >= lot_amount;
+    InitAuctionResult {
+        bids: SealedBids {
+            highest: 0,
+            second: 0,
+            winner: NO_BID,
+        },
+        new_lot_balance: if escrowed {
+            lot_balance.wrapping_sub(lot_amount)
+        } else {
+            lot_balance
+        },
+        escrowed,
+    }
+}
+
+/// Mirror of `circuits::BidInput`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BidInput {
+    pub amount: u64,
+}
+
+/// Result of [`submit_bid`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubmitBidResult {
+    pub is_valid: bool,
+    pub bids: SealedBids,
+    pub escrow: u64,
+    pub new_balance: u64,
+}
+
+/// Mirror of `circuits::submit_bid`
+pub fn submit_bid(
+    input: BidInput,
+    bids: SealedBids,
+    balance: u64,
+    bid_index: u8,
+    reserve_price: u64,
+) -> SubmitBidResult {
+    let is_valid = input.amount <= balance && input.amount >= reserve_price;
+    let amount = if is_valid { input.amount } else { 0 };
+
+    let new_bids = if amount > bids.highest {
+        SealedBids {
+            highest: amount,
+            second: bids.highest,
+            winner: bid_index,
+        }
+    } else if amount > bids.second {
+        SealedBids {
+            second: amount,
+            ..bids
+        }
+    } else {
+        bids
+    };
+
+    SubmitBidResult {
+        is_valid,
+        bids: new_bids,
+        escrow: amount,
+        new_balance: balance.wrapping_sub(amount),
+    }
+}
+
+/// Mirror of `circuits::resolve_auction`, returns `(winner, price)`
+pub fn resolve_auction(bids: SealedBids, second_price: bool, reserve_price: u64) -> (u8, u64) {
+    let bid_price = if second_price {
+        bids.second
+    } else {
+        bids.highest
+    };
+    let price = if bids.winner == NO_BID {
+        0
+    } else {
+        bid_price.max(reserve_price)
+    };
+    (bids.winner, price)
+}
+
+/// Result of [`settle_bid`] and [`settle_auction`], one field per circuit output
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuctionSettlementResult {
+    pub new_bid_balance: u64,
+    pub new_lot_balance: u64,
+    pub settled: bool,
+}
+
+/// Mirror of `circuits::settle_bid`
+pub fn settle_bid(
+    escrow: u64,
+    bid_balance: u64,
+    lot_balance: u64,
+    won: bool,
+    price: u64,
+    lot_amount: u64,
+) -> AuctionSettlementResult {
+    let paid = if won { price } else { 0 };
+    let lot_credit = if won { lot_amount } else { 0 };
+    let covered = escrow >= paid;
+    let refund = if covered {
+        escrow.wrapping_sub(paid)
+    } else {
+        0
+    };
+
+    let new_bid_wide = bid_balance as u128 + refund as u128;
+    let new_lot_wide = lot_balance as u128 + lot_credit as u128;
+    let settled = covered && new_bid_wide <= u64::MAX as u128 && new_lot_wide <= u64::MAX as u128;
+
+    let (new_bid_balance, new_lot_balance) = if settled {
+        (new_bid_wide as u64, new_lot_wide as u64)
+    } else {
+        (bid_balance, lot_balance)
+    };
+
+    AuctionSettlementResult {
+        new_bid_balance,
+        new_lot_balance,
+        settled,
+    }
+}
+
+/// Mirror of `circuits::settle_auction`
+pub fn settle_auction(
+    bid_balance: u64,
+    lot_balance: u64,
+    sold: bool,
+    price: u64,
+    lot_amount: u64,
+) -> AuctionSettlementResult {
+    let bid_credit = if sold { price } else { 0 };
+    let lot_credit = if sold { 0 } else { lot_amount };
+
+    let new_bid_wide = bid_balance as u128 + bid_credit as u128;
+    let new_lot_wide = lot_balance as u128 + lot_credit as u128;
+    let settled = new_bid_wide <= u64::MAX as u128 && new_lot_wide <= u64::MAX as u128;
+
+    let (new_bid_balance, new_lot_balance) = if settled {
+        (new_bid_wide as u64, new_lot_wide as u64)
+    } else {
+        (bid_balance, lot_balance)
+    };
+
+    AuctionSettlementResult {
+        new_bid_balance,
+        new_lot_balance,
+        settled,
+    }
+}
+
+/// Mirror of `circuits::disclose_balance`
+pub fn disclose_balance(balance: u64) -> u64 {
+    balance
+}
@@ -0,0 +1,118 @@
+//! Checks the mirror against the `encrypted-ixs` circuit source
+//!
+//! Parses both files as text, so it runs without `arcis`. Every
+//! `#[instruction]` needs a mirror function of the same name, every circuit
+//! struct a mirror struct with the same fields in the same order, and every
+//! circuit constant a mirror constant with the same type and value. The
+//! mirror may define extra items, such as the `*Result` structs.
+
+use std::collections::BTreeMap;
+
+const CIRCUITS: &str = include_str!("../../encrypted-ixs/src/lib.rs");
+const MIRROR: &str = include_str!("../src/lib.rs");
+
+/// Names of the functions following an `#[instruction]` attribute
+fn instructions(source: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if line != "#[instruction]" {
+            continue;
+        }
+        if let Some(name) = lines.next().and_then(fn_name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Names of all `pub fn`s
+fn functions(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| fn_name(line.trim()))
+        .collect()
+}
+
+fn fn_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("pub fn ")?;
+    Some(rest.split(['(', '<']).next()?.to_string())
+}
+
+/// `pub struct`s with their `(field, type)` list, doc comments skipped
+fn structs(source: &str) -> BTreeMap<String, Vec<(String, String)>> {
+    let mut structs = BTreeMap::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_suffix(" {"))
+        else {
+            continue;
+        };
+        let mut fields = Vec::new();
+        for line in lines.by_ref() {
+            if line == "}" {
+                break;
+            }
+            if line.starts_with("//") || line.is_empty() {
+                continue;
+            }
+            let field = line.strip_prefix("pub ").unwrap_or(line);
+            let field = field.trim_end_matches(',');
+            let (field, ty) = field.split_once(": ").expect("field is `name: Type`");
+            fields.push((field.to_string(), ty.to_string()));
+        }
+        structs.insert(name.to_string(), fields);
+    }
+    structs
+}
+
+/// `pub const`s as `name -> "Type = value"`
+fn constants(source: &str) -> BTreeMap<String, String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("pub const ")?.strip_suffix(';')?;
+            let (name, definition) = rest.split_once(": ")?;
+            Some((name.to_string(), definition.to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn every_instruction_is_mirrored() {
+    let circuits = instructions(CIRCUITS);
+    assert!(!circuits.is_empty(), "no #[instruction] found in circuits");
+    let mirror = functions(MIRROR);
+    for name in circuits {
+        assert!(mirror.contains(&name), "no mirror for instruction `{name}`");
+    }
+}
+
+#[test]
+fn every_struct_is_mirrored_field_for_field() {
+    let circuits = structs(CIRCUITS);
+    assert!(!circuits.is_empty(), "no structs found in circuits");
+    let mirror = structs(MIRROR);
+    for (name, fields) in circuits {
+        let Some(mirrored) = mirror.get(&name) else {
+            panic!("no mirror for struct `{name}`");
+        };
+        assert_eq!(&fields, mirrored, "fields of `{name}` differ");
+    }
+}
+
+#[test]
+fn every_constant_is_mirrored() {
+    let circuits = constants(CIRCUITS);
+    assert!(!circuits.is_empty(), "no constants found in circuits");
+    let mirror = constants(MIRROR);
+    for (name, definition) in circuits {
+        assert_eq!(
+            mirror.get(&name),
+            Some(&definition),
+            "constant `{name}` differs"
+        );
+    }
+}
@@ -0,0 +1,1233 @@
+//! Property tests for the circuits, run against the plaintext mirror
+
+use circuits_plaintext::*;
+use proptest::prelude::*;
+
+/// Recipient address limbs as the program passes them to `private_transfer`
+const OWNER_LO: u128 = 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef;
+const OWNER_HI: u128 = 0xfedc_ba98_7654_3210_fedc_ba98_7654_3210;
+/// Sender balance sequence the program passes to `private_transfer`
+const SEQUENCE: u64 = 7;
+/// Highest `Treasury::fee_bps` the program accepts
+const MAX_FEE_BPS: u16 = 1_000;
+
+fn transfer_input(amount: u64, min_balance: u64) -> PrivateTransferInput {
+    PrivateTransferInput {
+        amount,
+        min_balance,
+        recipient_lo: OWNER_LO,
+        recipient_hi: OWNER_HI,
+        sequence: SEQUENCE,
+    }
+}
+
+/// `private_transfer` against the current sequence and the expected recipient's high limb
+fn transfer(
+    input: PrivateTransferInput,
+    sender: u64,
+    recipient: u64,
+    recipient_lo: u128,
+    fee_bps: u16,
+) -> PrivateTransferResult {
+    private_transfer(
+        input,
+        sender,
+        SEQUENCE,
+        recipient,
+        recipient_lo,
+        OWNER_HI,
+        fee_bps,
+    )
+}
+
+fn batch_input(amounts: [u64; BATCH_SIZE], min_balance: u64) -> BatchTransferInput {
+    BatchTransferInput {
+        amounts,
+        min_balance,
+        recipients_lo: [OWNER_LO; BATCH_SIZE],
+        recipients_hi: [OWNER_HI; BATCH_SIZE],
+        sequence: SEQUENCE,
+    }
+}
+
+/// `batch_transfer` against the current sequence, with per-slot owner low limbs
+fn batch(
+    input: BatchTransferInput,
+    sender: u64,
+    recipients: [u64; BATCH_SIZE],
+    owners_lo: [u128; BATCH_SIZE],
+    fee_bps: u16,
+) -> BatchTransferResult {
+    batch_transfer(
+        input,
+        sender,
+        SEQUENCE,
+        recipients,
+        owners_lo,
+        [OWNER_HI; BATCH_SIZE],
+        fee_bps,
+    )
+}
+
+#[test]
+fn create_balance_starts_at_zero() {
+    assert_eq!(create_balance(), (0, 0));
+}
+
+/// An order placed by an owner who can cover any escrow
+fn order(is_buy: bool, price: u64, size: u64) -> OrderState {
+    let input = OrderInput {
+        is_buy,
+        price,
+        size,
+    };
+    let placed = place_order(input, u64::MAX, u64::MAX);
+    assert!(placed.is_valid);
+    placed.order
+}
+
+/// Orders whose notional always fits a `u64`, priced around 1.0
+fn order_strategy() -> impl Strategy<Value = OrderState> {
+    (
+        any::<bool>(),
+        0..=4 * PRICE_SCALE as u64,
+        0..=u32::MAX as u64,
+    )
+        .prop_map(|(is_buy, price, size)| order(is_buy, price, size))
+}
+
+/// Base units an order holds, as escrow or as proceeds
+fn base_held(order: &OrderState) -> u128 {
+    if order.is_buy {
+        order.proceeds as u128
+    } else {
+        order.escrow as u128
+    }
+}
+
+/// Quote units an order holds, as escrow or as proceeds
+fn quote_held(order: &OrderState) -> u128 {
+    if order.is_buy {
+        order.escrow as u128
+    } else {
+        order.proceeds as u128
+    }
+}
+
+fn auction_orders() -> impl Strategy<Value = [OrderState; AUCTION_SIZE]> {
+    // Few distinct prices so orders often cross, off the scale so payments round
+    let step = PRICE_SCALE as u64 / 3;
+    let order = (any::<bool>(), 1..=5u64, 0..=1_000u64)
+        .prop_map(move |(is_buy, tick, size)| order(is_buy, tick * step, size));
+    prop::array::uniform4(order)
+}
+
+/// Base units matched if every order traded at `price`
+fn volume_at(orders: &[OrderState; AUCTION_SIZE], price: u64) -> u128 {
+    let crosses = |o: &&OrderState| {
+        if o.is_buy {
+            o.price >= price
+        } else {
+            o.price <= price
+        }
+    };
+    let side = |is_buy: bool| -> u128 {
+        orders
+            .iter()
+            .filter(|o| o.is_buy == is_buy && crosses(o))
+            .map(|o| o.remaining as u128)
+            .sum()
+    };
+    side(true).min(side(false))
+}
+
+#[test]
+fn init_pool_starts_empty() {
+    assert_eq!(
+        init_pool(),
+        PoolReserves {
+            reserve_a: 0,
+            reserve_b: 0
+        }
+    );
+}
+
+#[test]
+fn auction_refuses_an_uncovered_fill() {
+    let price = PRICE_SCALE as u64;
+    let mut buy = order(true, price, 100);
+    buy.escrow = 99;
+    let orders = [
+        buy,
+        order(false, price, 100),
+        order(false, price, 0),
+        order(true, 0, 0),
+    ];
+    let out = batch_auction(orders);
+    assert!(!out.cleared);
+    assert_eq!(out.new_orders, orders);
+    assert!(out.fills.iter().all(|fill| fill.filled == 0));
+}
+
+/// Fold `bids` into a fresh sealed-bid auction with `reserve_price` in
+/// submission order, each from a bidder who can cover it
+fn sealed_auction(bids: &[u64], reserve_price: u64) -> SealedBids {
+    bids.iter()
+        .enumerate()
+        .fold(init_auction(0, 0).bids, |standing, (i, &amount)| {
+            submit_bid(
+                BidInput { amount },
+                standing,
+                u64::MAX,
+                i as u8,
+                reserve_price,
+            )
+            .bids
+        })
+}
+
+proptest! {
+    #[test]
+    fn deposit_credits_amount(balance in 0..=u64::MAX / 2, amount in 0..=u64::MAX / 2) {
+        prop_assert_eq!(deposit(balance, amount), balance + amount);
+    }
+
+    #[test]
+    fn withdraw_conserves_value(balance: u64, amount: u64) {
+        let (new_balance, released) = withdraw(balance, amount);
+        prop_assert_eq!(new_balance as u128 + released as u128, balance as u128);
+        prop_assert!(new_balance <= balance);
+    }
+
+    #[test]
+    fn withdraw_releases_all_or_nothing(balance: u64, amount: u64) {
+        let (new_balance, released) = withdraw(balance, amount);
+        if balance >= amount {
+            prop_assert_eq!(released, amount);
+        } else {
+            prop_assert_eq!(released, 0);
+            prop_assert_eq!(new_balance, balance);
+        }
+    }
+
+    #[test]
+    fn claim_incoming_conserves_value(balance: u64, incoming: u64) {
+        let (new_balance, new_incoming, claim) = claim_incoming(balance, incoming);
+        prop_assert_eq!(
+            new_balance as u128 + new_incoming as u128,
+            balance as u128 + incoming as u128
+        );
+        prop_assert_eq!(claim.new_balance, new_balance);
+        prop_assert_eq!(claim.claimed, new_balance - balance);
+    }
+
+    #[test]
+    fn claim_incoming_moves_everything_or_nothing(balance: u64, incoming: u64) {
+        let (new_balance, new_incoming, _) = claim_incoming(balance, incoming);
+        if incoming <= u64::MAX - balance {
+            prop_assert_eq!(new_incoming, 0);
+        } else {
+            prop_assert_eq!((new_balance, new_incoming), (balance, incoming));
+        }
+    }
+
+    #[test]
+    fn sweep_fee_conserves_value(balance: u64, fee: u64) {
+        let (new_balance, swept) = sweep_fee(balance, fee);
+        let left = if swept { 0 } else { fee };
+        prop_assert_eq!(new_balance as u128 + left as u128, balance as u128 + fee as u128);
+    }
+
+    #[test]
+    fn sweep_fee_only_refuses_on_overflow(balance: u64, fee: u64) {
+        let (new_balance, swept) = sweep_fee(balance, fee);
+        prop_assert_eq!(swept, balance.checked_add(fee).is_some());
+        if !swept {
+            prop_assert_eq!(new_balance, balance);
+        }
+    }
+
+    #[test]
+    fn transfer_conserves_value(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+        matches: bool,
+    ) {
+        let lo = if matches { OWNER_LO } else { !OWNER_LO };
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, lo, fee_bps);
+        prop_assert_eq!(
+            r.new_sender_balance as u128 + r.new_incoming as u128 + r.fee as u128,
+            sender as u128 + recipient as u128
+        );
+    }
+
+    #[test]
+    fn transfer_never_wraps(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, OWNER_LO, fee_bps);
+        prop_assert!(r.new_sender_balance <= sender);
+        prop_assert!(r.new_incoming >= recipient);
+        prop_assert_eq!(r.fee, r.output.fee);
+        if r.output.is_valid {
+            prop_assert_eq!(r.new_sender_balance, sender - amount - r.output.fee);
+            prop_assert!(r.new_sender_balance >= min_balance);
+            prop_assert_eq!(r.new_incoming, recipient + amount);
+        }
+    }
+
+    #[test]
+    fn transfer_fee_is_rounded_down_bps_of_amount(
+        amount in 0..=u64::MAX / 4,
+        sender in u64::MAX / 2..=u64::MAX,
+        recipient in 0..=u64::MAX / 4,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = transfer(transfer_input(amount, 0), sender, recipient, OWNER_LO, fee_bps);
+        prop_assert!(r.output.is_valid);
+        prop_assert_eq!(r.output.fee as u128, amount as u128 * fee_bps as u128 / 10_000);
+        prop_assert!(r.output.fee <= amount);
+    }
+
+    #[test]
+    fn invalid_transfer_leaves_balances_unchanged(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+        lo: u128,
+    ) {
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, lo, fee_bps);
+        if !r.output.is_valid {
+            prop_assert_eq!(r.new_sender_balance, sender);
+            prop_assert_eq!(r.new_incoming, recipient);
+            prop_assert_eq!(r.fee, 0);
+            prop_assert_eq!(r.receipt.amount, 0);
+            prop_assert_eq!(r.output.fee, 0);
+            prop_assert_ne!(r.output.error_code, TRANSFER_OK);
+        }
+    }
+
+    #[test]
+    fn transfer_outputs_agree(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, OWNER_LO, fee_bps);
+        prop_assert_eq!(r.output.new_sender_balance, r.new_sender_balance);
+        prop_assert_eq!(r.receipt.new_incoming, r.new_incoming);
+        prop_assert_eq!(r.output.is_valid, r.output.error_code == TRANSFER_OK);
+    }
+
+    #[test]
+    fn transfer_rejects_min_balance_above_balance(
+        amount: u64,
+        sender in 0..u64::MAX,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let min_balance = sender + 1;
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, OWNER_LO, fee_bps);
+        prop_assert!(!r.output.is_valid);
+        prop_assert_eq!(r.new_sender_balance, sender);
+    }
+
+    #[test]
+    fn transfer_rejects_wrong_recipient(
+        amount in 0..=u64::MAX / 4,
+        sender in u64::MAX / 2..=u64::MAX,
+        recipient in 0..=u64::MAX / 4,
+        lo: u128,
+    ) {
+        prop_assume!(lo != OWNER_LO);
+        let r = transfer(transfer_input(amount, 0), sender, recipient, lo, MAX_FEE_BPS);
+        prop_assert_eq!(r.output.error_code, TRANSFER_RECIPIENT_MISMATCH);
+    }
+
+    #[test]
+    fn transfer_rejects_stale_sequence(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        sequence: u64,
+    ) {
+        prop_assume!(sequence != SEQUENCE);
+        let r = private_transfer(
+            transfer_input(amount, min_balance),
+            sender,
+            sequence,
+            recipient,
+            OWNER_LO,
+            OWNER_HI,
+            MAX_FEE_BPS,
+        );
+        prop_assert_eq!(r.output.error_code, TRANSFER_STALE_SEQUENCE);
+        prop_assert_eq!(r.new_sender_balance, sender);
+        prop_assert_eq!(r.new_incoming, recipient);
+    }
+
+    #[test]
+    fn transfer_error_code_classification(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+        matches: bool,
+    ) {
+        let lo = if matches { OWNER_LO } else { !OWNER_LO };
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, lo, fee_bps);
+        let fee = amount as u128 * fee_bps as u128 / 10_000;
+        let required = amount as u128 + fee + min_balance as u128;
+        let expected = if required > u64::MAX as u128
+            || amount as u128 + recipient as u128 > u64::MAX as u128
+        {
+            TRANSFER_OVERFLOW
+        } else if (sender as u128) < required {
+            TRANSFER_INSUFFICIENT_FUNDS
+        } else if !matches {
+            TRANSFER_RECIPIENT_MISMATCH
+        } else {
+            TRANSFER_OK
+        };
+        prop_assert_eq!(r.output.error_code, expected);
+    }
+
+    #[test]
+    fn batch_conserves_value(
+        amounts: [u64; BATCH_SIZE],
+        min_balance: u64,
+        sender: u64,
+        recipients: [u64; BATCH_SIZE],
+        fee_bps in 0..=MAX_FEE_BPS,
+        owners_lo: [u128; BATCH_SIZE],
+    ) {
+        let r = batch(batch_input(amounts, min_balance), sender, recipients, owners_lo, fee_bps);
+        let total = |sender: u64, recipients: [u64; BATCH_SIZE], fee: u64| {
+            sender as u128 + recipients.iter().map(|&b| b as u128).sum::<u128>() + fee as u128
+        };
+        prop_assert_eq!(
+            total(r.new_sender_balance, r.new_incoming, r.fee),
+            total(sender, recipients, 0)
+        );
+        prop_assert_eq!(r.fee, r.output.fee);
+        prop_assert_eq!(r.output.new_sender_balance, r.new_sender_balance);
+        prop_assert_eq!(r.output.is_valid, r.output.error_code == TRANSFER_OK);
+    }
+
+    #[test]
+    fn viewing_outputs_mirror_the_transfer(
+        amount: u64,
+        min_balance: u64,
+        sender: u64,
+        recipient: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = transfer(transfer_input(amount, min_balance), sender, recipient, OWNER_LO, fee_bps);
+        prop_assert_eq!(r.credit_view, r.receipt);
+        prop_assert_eq!(r.debit_view.amount, r.receipt.amount);
+        prop_assert_eq!(r.debit_view.fee, r.output.fee);
+        prop_assert_eq!(r.debit_view.new_sender_balance, r.new_sender_balance);
+        prop_assert_eq!(
+            r.debit_view.amount as u128 + r.debit_view.fee as u128,
+            sender as u128 - r.new_sender_balance as u128
+        );
+    }
+
+    #[test]
+    fn batch_viewing_outputs_account_for_every_slot(
+        amounts: [u64; BATCH_SIZE],
+        min_balance: u64,
+        sender: u64,
+        recipients: [u64; BATCH_SIZE],
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = batch(batch_input(amounts, min_balance), sender, recipients, [OWNER_LO; BATCH_SIZE], fee_bps);
+        let sent: u128 = r.debit_view.amounts.iter().map(|&a| a as u128).sum();
+        prop_assert_eq!(sent + r.debit_view.fee as u128, sender as u128 - r.new_sender_balance as u128);
+        prop_assert_eq!(r.debit_view.new_sender_balance, r.new_sender_balance);
+        for i in 0..BATCH_SIZE {
+            prop_assert_eq!(r.credit_views[i].amount, r.debit_view.amounts[i]);
+            prop_assert_eq!(r.credit_views[i].new_incoming, r.new_incoming[i]);
+        }
+    }
+
+    #[test]
+    fn batch_is_all_or_nothing(
+        amounts in prop::array::uniform4(0..=u64::MAX / 8),
+        min_balance in 0..=u64::MAX / 8,
+        sender: u64,
+        recipients in prop::array::uniform4(0..=u64::MAX / 2),
+        fee_bps in 0..=MAX_FEE_BPS,
+        mismatched in 0..=BATCH_SIZE,
+    ) {
+        // `mismatched == BATCH_SIZE` leaves every slot owned by the encrypted recipient
+        let mut owners_lo = [OWNER_LO; BATCH_SIZE];
+        if mismatched < BATCH_SIZE {
+            owners_lo[mismatched] = !OWNER_LO;
+        }
+        let r = batch(batch_input(amounts, min_balance), sender, recipients, owners_lo, fee_bps);
+        if r.output.is_valid {
+            for i in 0..BATCH_SIZE {
+                prop_assert_eq!(r.new_incoming[i], recipients[i] + amounts[i]);
+            }
+            prop_assert!(r.new_sender_balance >= min_balance);
+        } else {
+            prop_assert_eq!(r.new_sender_balance, sender);
+            prop_assert_eq!(r.new_incoming, recipients);
+            prop_assert_eq!(r.fee, 0);
+            prop_assert_eq!(r.output.fee, 0);
+        }
+    }
+
+    #[test]
+    fn batch_fee_is_sum_of_single_transfer_fees(
+        amounts in prop::array::uniform4(0..=u64::MAX / 8),
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let r = batch(batch_input(amounts, 0), u64::MAX, [0; BATCH_SIZE], [OWNER_LO; BATCH_SIZE], fee_bps);
+        prop_assert!(r.output.is_valid);
+        let single_fees: u64 = amounts
+            .iter()
+            .map(|&amount| transfer(transfer_input(amount, 0), u64::MAX, 0, OWNER_LO, fee_bps).output.fee)
+            .sum();
+        prop_assert_eq!(r.output.fee, single_fees);
+    }
+
+    #[test]
+    fn batch_zero_slots_skip_recipient_check(
+        amount in 1..=u64::MAX / 8,
+        sender in u64::MAX / 2..=u64::MAX,
+        recipients in prop::array::uniform4(0..=u64::MAX / 2),
+        decoy_lo: u128,
+    ) {
+        prop_assume!(decoy_lo != OWNER_LO);
+        let mut amounts = [0; BATCH_SIZE];
+        amounts[0] = amount;
+        let mut owners_lo = [decoy_lo; BATCH_SIZE];
+        owners_lo[0] = OWNER_LO;
+        let r = batch(batch_input(amounts, 0), sender, recipients, owners_lo, MAX_FEE_BPS);
+        prop_assert!(r.output.is_valid);
+        prop_assert_eq!(&r.new_incoming[1..], &recipients[1..]);
+
+        // The same decoy in a paying slot is rejected
+        amounts[1] = amount;
+        let r = batch(batch_input(amounts, 0), sender, recipients, owners_lo, MAX_FEE_BPS);
+        prop_assert_eq!(r.output.error_code, TRANSFER_RECIPIENT_MISMATCH);
+    }
+
+    #[test]
+    fn check_balance_matches_comparison(balance: u64, minimum: u64) {
+        prop_assert_eq!(check_balance(BalanceCheckInput { minimum }, balance), balance >= minimum);
+    }
+
+    #[test]
+    fn swap_never_wraps(
+        input_amount: u64,
+        min_output: u64,
+        actual_output: u64,
+        input_balance: u64,
+        output_balance: u64,
+    ) {
+        let input = ConfidentialSwapInput { input_amount, min_output, actual_output };
+        let out = validate_swap(input, input_balance, output_balance);
+        prop_assert!(out.new_input_balance <= input_balance);
+        prop_assert!(out.new_output_balance >= output_balance);
+        prop_assert_eq!(out.slippage_ok, actual_output >= min_output);
+        if out.is_valid {
+            prop_assert_eq!(out.new_input_balance, input_balance - input_amount);
+            prop_assert_eq!(out.new_output_balance, output_balance + actual_output);
+        }
+    }
+
+    #[test]
+    fn swap_applies_only_when_valid(
+        input_amount: u64,
+        min_output: u64,
+        actual_output: u64,
+        input_balance: u64,
+        output_balance: u64,
+    ) {
+        let input = ConfidentialSwapInput { input_amount, min_output, actual_output };
+        let out = validate_swap(input, input_balance, output_balance);
+        let expected_valid = input_balance >= input_amount
+            && actual_output >= min_output
+            && output_balance as u128 + actual_output as u128 <= u64::MAX as u128;
+        prop_assert_eq!(out.is_valid, expected_valid);
+        if !out.is_valid {
+            prop_assert_eq!(out.new_input_balance, input_balance);
+            prop_assert_eq!(out.new_output_balance, output_balance);
+        }
+    }
+
+    #[test]
+    fn swap_with_bad_slippage_does_not_debit(
+        input_amount: u64,
+        actual_output in 0..u64::MAX,
+        input_balance: u64,
+        output_balance: u64,
+    ) {
+        prop_assume!(input_balance >= input_amount);
+        let min_output = actual_output + 1;
+        let input = ConfidentialSwapInput { input_amount, min_output, actual_output };
+        let out = validate_swap(input, input_balance, output_balance);
+        prop_assert!(!out.is_valid);
+        prop_assert_eq!(out.new_input_balance, input_balance);
+    }
+
+    #[test]
+    fn oracle_swap_output_is_rounded_down_price(
+        input_amount: u64,
+        min_output: u64,
+        price: u64,
+        input_balance: u64,
+        output_balance: u64,
+    ) {
+        let input = OracleSwapInput { input_amount, min_output };
+        let out = validate_oracle_swap(input, input_balance, output_balance, price);
+        let expected = input_amount as u128 * price as u128 / 1_000_000_000;
+        prop_assert_eq!(out.expected_output as u128, expected.min(u64::MAX as u128));
+        prop_assert_eq!(out.slippage_ok, out.expected_output >= min_output);
+    }
+
+    #[test]
+    fn oracle_swap_is_valid_only_when_every_check_holds(
+        input_amount: u64,
+        min_output: u64,
+        price: u64,
+        input_balance: u64,
+        output_balance: u64,
+    ) {
+        let input = OracleSwapInput { input_amount, min_output };
+        let out = validate_oracle_swap(input, input_balance, output_balance, price);
+        let expected = input_amount as u128 * price as u128 / 1_000_000_000;
+        let expected_valid = input_balance >= input_amount
+            && expected >= min_output as u128
+            && output_balance as u128 + expected <= u64::MAX as u128;
+        prop_assert_eq!(out.is_valid, expected_valid);
+    }
+
+    #[test]
+    fn add_liquidity_conserves_value(
+        amount_a: u64,
+        amount_b: u64,
+        provider_a: u64,
+        provider_b: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+    ) {
+        let input = LiquidityInput { amount_a, amount_b };
+        let out = add_liquidity(input, provider_a, provider_b, PoolReserves { reserve_a, reserve_b });
+        let expected_valid = provider_a >= amount_a
+            && provider_b >= amount_b
+            && reserve_a as u128 + amount_a as u128 <= u64::MAX as u128
+            && reserve_b as u128 + amount_b as u128 <= u64::MAX as u128;
+        prop_assert_eq!(out.is_valid, expected_valid);
+        prop_assert_eq!(
+            out.new_provider_a as u128 + out.new_reserves.reserve_a as u128,
+            provider_a as u128 + reserve_a as u128
+        );
+        prop_assert_eq!(
+            out.new_provider_b as u128 + out.new_reserves.reserve_b as u128,
+            provider_b as u128 + reserve_b as u128
+        );
+    }
+
+    #[test]
+    fn remove_liquidity_conserves_value(
+        amount_a: u64,
+        amount_b: u64,
+        provider_a: u64,
+        provider_b: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+    ) {
+        let input = LiquidityInput { amount_a, amount_b };
+        let out =
+            remove_liquidity(input, provider_a, provider_b, PoolReserves { reserve_a, reserve_b });
+        let expected_valid = reserve_a >= amount_a
+            && reserve_b >= amount_b
+            && provider_a as u128 + amount_a as u128 <= u64::MAX as u128
+            && provider_b as u128 + amount_b as u128 <= u64::MAX as u128;
+        prop_assert_eq!(out.is_valid, expected_valid);
+        prop_assert_eq!(
+            out.new_provider_a as u128 + out.new_reserves.reserve_a as u128,
+            provider_a as u128 + reserve_a as u128
+        );
+        prop_assert_eq!(
+            out.new_provider_b as u128 + out.new_reserves.reserve_b as u128,
+            provider_b as u128 + reserve_b as u128
+        );
+    }
+
+    #[test]
+    fn remove_liquidity_undoes_add_liquidity(
+        amount_a in 0..u64::MAX / 2,
+        amount_b in 0..u64::MAX / 2,
+        spare_a in 0..u64::MAX / 2,
+        spare_b in 0..u64::MAX / 2,
+        reserve_a in 0..u64::MAX / 2,
+        reserve_b in 0..u64::MAX / 2,
+    ) {
+        let (provider_a, provider_b) = (amount_a + spare_a, amount_b + spare_b);
+        let input = LiquidityInput { amount_a, amount_b };
+        let added = add_liquidity(input, provider_a, provider_b, PoolReserves { reserve_a, reserve_b });
+        prop_assert!(added.is_valid);
+        let removed =
+            remove_liquidity(input, added.new_provider_a, added.new_provider_b, added.new_reserves);
+        prop_assert!(removed.is_valid);
+        prop_assert_eq!(removed.new_provider_a, provider_a);
+        prop_assert_eq!(removed.new_provider_b, provider_b);
+        prop_assert_eq!(removed.new_reserves, PoolReserves { reserve_a, reserve_b });
+    }
+
+    #[test]
+    fn cpmm_swap_conserves_each_mint(
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+        trader_a: u64,
+        trader_b: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let input = CpmmSwapInput { amount_in, min_amount_out, a_to_b };
+        let reserves = PoolReserves { reserve_a, reserve_b };
+        let out = swap_cpmm(input, trader_a, trader_b, reserves, fee_bps);
+        prop_assert_eq!(
+            out.new_trader_a as u128 + out.new_reserves.reserve_a as u128,
+            trader_a as u128 + reserve_a as u128
+        );
+        prop_assert_eq!(
+            out.new_trader_b as u128 + out.new_reserves.reserve_b as u128,
+            trader_b as u128 + reserve_b as u128
+        );
+    }
+
+    #[test]
+    fn cpmm_swap_never_decreases_k(
+        amount_in: u64,
+        a_to_b: bool,
+        trader_a: u64,
+        trader_b: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let input = CpmmSwapInput { amount_in, min_amount_out: 0, a_to_b };
+        let reserves = PoolReserves { reserve_a, reserve_b };
+        let out = swap_cpmm(input, trader_a, trader_b, reserves, fee_bps);
+        let k = reserve_a as u128 * reserve_b as u128;
+        let new_k = out.new_reserves.reserve_a as u128 * out.new_reserves.reserve_b as u128;
+        prop_assert!(new_k >= k);
+        if out.output.is_valid {
+            prop_assert!(out.new_reserves.reserve_a > 0 && out.new_reserves.reserve_b > 0);
+        }
+    }
+
+    #[test]
+    fn cpmm_swap_applies_only_when_valid(
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+        trader_a: u64,
+        trader_b: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        let input = CpmmSwapInput { amount_in, min_amount_out, a_to_b };
+        let reserves = PoolReserves { reserve_a, reserve_b };
+        let out = swap_cpmm(input, trader_a, trader_b, reserves, fee_bps);
+        let (balance_in, reserve_in, reserve_out) = if a_to_b {
+            (trader_a, reserve_a, reserve_b)
+        } else {
+            (trader_b, reserve_b, reserve_a)
+        };
+        let fee = amount_in as u128 * fee_bps as u128 / 10_000;
+        let net_in = amount_in as u128 - fee;
+        let expected_out = if reserve_in as u128 + net_in == 0 {
+            0
+        } else {
+            reserve_out as u128 * net_in / (reserve_in as u128 + net_in)
+        };
+        if out.output.is_valid {
+            prop_assert!(balance_in >= amount_in);
+            prop_assert_eq!(out.output.amount_out as u128, expected_out);
+            prop_assert_eq!(out.output.fee as u128, fee);
+            prop_assert!(out.output.amount_out >= min_amount_out.max(1));
+        } else {
+            prop_assert_eq!(out.output, CpmmSwapOutput { is_valid: false, amount_out: 0, fee: 0 });
+            prop_assert_eq!((out.new_trader_a, out.new_trader_b), (trader_a, trader_b));
+            prop_assert_eq!(out.new_reserves, reserves);
+        }
+    }
+
+    #[test]
+    fn cpmm_swap_rejects_empty_pool(
+        amount_in: u64,
+        a_to_b: bool,
+        trader_a: u64,
+        trader_b: u64,
+        reserve: u64,
+    ) {
+        let input = CpmmSwapInput { amount_in, min_amount_out: 0, a_to_b };
+        let reserves = PoolReserves { reserve_a: reserve, reserve_b: 0 };
+        let out = swap_cpmm(input, trader_a, trader_b, reserves, 0);
+        prop_assert!(!out.output.is_valid);
+    }
+
+    #[test]
+    fn place_order_escrows_the_input_side(
+        is_buy: bool,
+        price: u64,
+        size: u64,
+        base_balance: u64,
+        quote_balance: u64,
+    ) {
+        let input = OrderInput { is_buy, price, size };
+        let out = place_order(input, base_balance, quote_balance);
+        let notional = size as u128 * price as u128 / PRICE_SCALE;
+        let expected_valid = if is_buy {
+            notional <= quote_balance as u128
+        } else {
+            size <= base_balance
+        };
+        prop_assert_eq!(out.is_valid, expected_valid);
+        prop_assert_eq!(
+            out.new_base_balance as u128 + base_held(&out.order),
+            base_balance as u128
+        );
+        prop_assert_eq!(
+            out.new_quote_balance as u128 + quote_held(&out.order),
+            quote_balance as u128
+        );
+        prop_assert_eq!(out.order.proceeds, 0);
+        if out.is_valid {
+            prop_assert_eq!(out.order.remaining, size);
+            prop_assert_eq!(out.order.escrow as u128, if is_buy { notional } else { size as u128 });
+        } else {
+            prop_assert_eq!(out.order.remaining, 0);
+            prop_assert_eq!(out.order.escrow, 0);
+        }
+    }
+
+    #[test]
+    fn match_conserves_each_mint(order_a in order_strategy(), order_b in order_strategy()) {
+        let out = match_orders(order_a, order_b);
+        prop_assert_eq!(
+            base_held(&out.new_order_a) + base_held(&out.new_order_b),
+            base_held(&order_a) + base_held(&order_b)
+        );
+        prop_assert_eq!(
+            quote_held(&out.new_order_a) + quote_held(&out.new_order_b),
+            quote_held(&order_a) + quote_held(&order_b)
+        );
+    }
+
+    #[test]
+    fn match_fills_both_orders_equally(order_a in order_strategy(), order_b in order_strategy()) {
+        let out = match_orders(order_a, order_b);
+        let filled = out.fill_a.filled;
+        prop_assert_eq!(out.fill_b.filled, filled);
+        prop_assert_eq!(out.new_order_a.remaining, order_a.remaining - filled);
+        prop_assert_eq!(out.new_order_b.remaining, order_b.remaining - filled);
+        prop_assert_eq!(out.fill_a.remaining, out.new_order_a.remaining);
+        prop_assert_eq!(out.fill_b.remaining, out.new_order_b.remaining);
+        prop_assert_eq!(out.fill_a.proceeds, out.new_order_a.proceeds);
+        prop_assert_eq!(out.fill_b.proceeds, out.new_order_b.proceeds);
+        prop_assert_eq!(
+            (out.new_order_a.is_buy, out.new_order_a.price),
+            (order_a.is_buy, order_a.price)
+        );
+        prop_assert_eq!(
+            (out.new_order_b.is_buy, out.new_order_b.price),
+            (order_b.is_buy, order_b.price)
+        );
+    }
+
+    #[test]
+    fn match_requires_crossing_opposite_orders(
+        order_a in order_strategy(),
+        order_b in order_strategy(),
+    ) {
+        let out = match_orders(order_a, order_b);
+        let (buy, sell) = if order_a.is_buy { (order_a, order_b) } else { (order_b, order_a) };
+        let crosses = order_a.is_buy != order_b.is_buy && buy.price >= sell.price;
+        prop_assert_eq!(out.matched, crosses && order_a.remaining > 0 && order_b.remaining > 0);
+        if out.matched {
+            // The smaller order is filled completely
+            prop_assert_eq!(out.fill_a.filled, order_a.remaining.min(order_b.remaining));
+            prop_assert!(out.new_order_a.remaining == 0 || out.new_order_b.remaining == 0);
+        } else {
+            prop_assert_eq!(out.fill_a.filled, 0);
+            prop_assert_eq!((out.new_order_a, out.new_order_b), (order_a, order_b));
+        }
+    }
+
+    #[test]
+    fn match_trades_at_the_sell_price(order_a in order_strategy(), order_b in order_strategy()) {
+        let out = match_orders(order_a, order_b);
+        prop_assume!(out.matched);
+        let (sell, new_sell) = if order_a.is_buy {
+            (order_b, out.new_order_b)
+        } else {
+            (order_a, out.new_order_a)
+        };
+        let filled = out.fill_a.filled;
+        let cost = filled as u128 * sell.price as u128 / PRICE_SCALE;
+        prop_assert_eq!((new_sell.proceeds - sell.proceeds) as u128, cost);
+    }
+
+    #[test]
+    fn buy_escrow_covers_remaining_size(
+        buy_price in 0..=4 * PRICE_SCALE as u64,
+        buy_size in 0..=u32::MAX as u64,
+        sells in prop::collection::vec((0..=4 * PRICE_SCALE as u64, 0..=u32::MAX as u64), 1..=4),
+    ) {
+        let mut buy = order(true, buy_price, buy_size);
+        for (price, size) in sells {
+            buy = match_orders(buy, order(false, price, size)).new_order_a;
+            let needed = buy.remaining as u128 * buy_price as u128 / PRICE_SCALE;
+            prop_assert!(buy.escrow as u128 >= needed);
+        }
+    }
+
+    #[test]
+    fn filled_orders_stop_matching(
+        a_is_buy: bool,
+        a_price in 0..=4 * PRICE_SCALE as u64,
+        b_price in 0..=4 * PRICE_SCALE as u64,
+        size in 0..=u32::MAX as u64,
+    ) {
+        let first = match_orders(order(a_is_buy, a_price, size), order(!a_is_buy, b_price, size));
+        let again = match_orders(first.new_order_a, first.new_order_b);
+        if first.matched {
+            prop_assert!(!again.matched);
+        }
+    }
+
+    #[test]
+    fn settle_order_conserves_value(
+        order in order_strategy(),
+        counterparty in order_strategy(),
+        base_balance: u64,
+        quote_balance: u64,
+        close: bool,
+    ) {
+        let order = match_orders(order, counterparty).new_order_a;
+        let out = settle_order(order, base_balance, quote_balance, close);
+        prop_assert_eq!(
+            out.new_base_balance as u128 + base_held(&out.new_order),
+            base_balance as u128 + base_held(&order)
+        );
+        prop_assert_eq!(
+            out.new_quote_balance as u128 + quote_held(&out.new_order),
+            quote_balance as u128 + quote_held(&order)
+        );
+        if out.settled {
+            prop_assert_eq!(out.new_order.proceeds, 0);
+            if close {
+                prop_assert_eq!((out.new_order.remaining, out.new_order.escrow), (0, 0));
+            } else {
+                prop_assert_eq!(out.new_order.remaining, order.remaining);
+                prop_assert_eq!(out.new_order.escrow, order.escrow);
+            }
+        } else {
+            prop_assert_eq!(out.new_order, order);
+        }
+    }
+
+    #[test]
+    fn settle_order_only_refuses_on_overflow(
+        order in order_strategy(),
+        counterparty in order_strategy(),
+        base_balance in 0..=u64::MAX / 2,
+        quote_balance in 0..=u64::MAX / 2,
+        close: bool,
+    ) {
+        let order = match_orders(order, counterparty).new_order_a;
+        prop_assert!(settle_order(order, base_balance, quote_balance, close).settled);
+    }
+
+    #[test]
+    fn auction_buys_equal_sells(orders in auction_orders()) {
+        let out = batch_auction(orders);
+        let bought: u128 = (0..AUCTION_SIZE)
+            .filter(|&i| orders[i].is_buy)
+            .map(|i| out.fills[i].filled as u128)
+            .sum();
+        let sold: u128 = (0..AUCTION_SIZE)
+            .filter(|&i| !orders[i].is_buy)
+            .map(|i| out.fills[i].filled as u128)
+            .sum();
+        prop_assert_eq!(bought, sold);
+        prop_assert_eq!(out.cleared, bought > 0);
+        for ((order, new_order), fill) in orders.iter().zip(out.new_orders).zip(out.fills) {
+            prop_assert_eq!(new_order.remaining, order.remaining - fill.filled);
+            prop_assert_eq!(fill.remaining, new_order.remaining);
+        }
+    }
+
+    #[test]
+    fn auction_conserves_each_mint(orders in auction_orders()) {
+        let out = batch_auction(orders);
+        let base = |orders: &[OrderState]| orders.iter().map(base_held).sum::<u128>();
+        let quote = |orders: &[OrderState]| orders.iter().map(quote_held).sum::<u128>();
+        prop_assert_eq!(base(&out.new_orders), base(&orders));
+        prop_assert_eq!(quote(&out.new_orders), quote(&orders));
+    }
+
+    #[test]
+    fn auction_settles_fills_at_the_clearing_price(orders in auction_orders()) {
+        let out = batch_auction(orders);
+        for ((order, new_order), fill) in orders.iter().zip(out.new_orders).zip(out.fills) {
+            let cost = (fill.filled as u128 * fill.clearing_price as u128 / PRICE_SCALE) as u64;
+            prop_assert_eq!(fill.proceeds, new_order.proceeds);
+            if order.is_buy {
+                prop_assert_eq!(new_order.escrow, order.escrow - cost);
+                prop_assert_eq!(new_order.proceeds, order.proceeds + fill.filled);
+            } else {
+                prop_assert_eq!(new_order.escrow, order.escrow - fill.filled);
+                prop_assert!(new_order.proceeds >= order.proceeds);
+            }
+        }
+    }
+
+    #[test]
+    fn auction_fills_respect_the_uniform_price(orders in auction_orders()) {
+        let out = batch_auction(orders);
+        let price = out.fills[0].clearing_price;
+        for (order, fill) in orders.iter().zip(out.fills) {
+            prop_assert_eq!(fill.clearing_price, price);
+            if fill.filled > 0 {
+                if order.is_buy {
+                    prop_assert!(order.price >= price);
+                } else {
+                    prop_assert!(order.price <= price);
+                }
+            }
+        }
+        if !out.cleared {
+            prop_assert_eq!(price, 0);
+        }
+    }
+
+    #[test]
+    fn auction_maximizes_volume(orders in auction_orders()) {
+        let out = batch_auction(orders);
+        let bought: u128 = (0..AUCTION_SIZE)
+            .filter(|&i| orders[i].is_buy)
+            .map(|i| out.fills[i].filled as u128)
+            .sum();
+        for candidate in orders {
+            prop_assert!(volume_at(&orders, candidate.price) <= bought);
+        }
+        if out.cleared {
+            prop_assert_eq!(volume_at(&orders, out.fills[0].clearing_price), bought);
+        }
+    }
+
+    #[test]
+    fn sealed_auction_awards_the_earliest_highest_bid(
+        bids in prop::collection::vec(any::<u64>(), 1..=8),
+    ) {
+        let highest = *bids.iter().max().unwrap();
+        let first = bids.iter().position(|&bid| bid == highest).unwrap();
+        let (winner, price) = resolve_auction(sealed_auction(&bids, 0), false, 0);
+        if highest == 0 {
+            prop_assert_eq!(winner, NO_BID);
+        } else {
+            prop_assert_eq!(winner as usize, first);
+        }
+        prop_assert_eq!(price, highest);
+    }
+
+    #[test]
+    fn vickrey_auction_charges_the_second_highest_bid_above_the_reserve(
+        bids in prop::collection::vec(any::<u64>(), 1..=8),
+        reserve_price: u64,
+    ) {
+        let mut eligible: Vec<u64> = bids
+            .iter()
+            .copied()
+            .filter(|&bid| bid > 0 && bid >= reserve_price)
+            .collect();
+        eligible.sort_unstable_by(|a, b| b.cmp(a));
+        let standing = sealed_auction(&bids, reserve_price);
+        let (first_winner, _) = resolve_auction(standing, false, reserve_price);
+        let (winner, price) = resolve_auction(standing, true, reserve_price);
+        prop_assert_eq!(winner, first_winner);
+        if eligible.is_empty() {
+            prop_assert_eq!(winner, NO_BID);
+            prop_assert_eq!(price, 0);
+        } else {
+            let second = eligible.get(1).copied().unwrap_or(0);
+            prop_assert_eq!(price, second.max(reserve_price));
+        }
+    }
+
+    #[test]
+    fn bids_below_the_reserve_never_win(
+        bids in prop::collection::vec(any::<u64>(), 1..=8),
+        reserve_price: u64,
+        second_price: bool,
+    ) {
+        let (winner, price) =
+            resolve_auction(sealed_auction(&bids, reserve_price), second_price, reserve_price);
+        if winner != NO_BID {
+            prop_assert!(bids[winner as usize] >= reserve_price);
+            prop_assert!(price >= reserve_price);
+            prop_assert!(price <= bids[winner as usize]);
+        }
+    }
+
+    #[test]
+    fn vickrey_auction_with_one_bid_clears_at_the_reserve(
+        reserve_price in 1..=u64::MAX / 2,
+        excess in 0..=u64::MAX / 2,
+    ) {
+        let bid = reserve_price + excess;
+        let (winner, price) =
+            resolve_auction(sealed_auction(&[bid], reserve_price), true, reserve_price);
+        prop_assert_eq!(winner, 0);
+        prop_assert_eq!(price, reserve_price);
+    }
+
+    #[test]
+    fn vickrey_auction_with_only_zeroed_rivals_clears_at_the_reserve(
+        reserve_price in 1..=u64::MAX / 2,
+        excess in 0..=u64::MAX / 2,
+        rivals in prop::collection::vec(1..=u64::MAX, 1..=7),
+    ) {
+        // Every rival bids more than it can cover, so each is folded in as zero
+        let mut standing = sealed_auction(&[reserve_price + excess], reserve_price);
+        for (i, &amount) in rivals.iter().enumerate() {
+            let out = submit_bid(BidInput { amount }, standing, amount - 1, i as u8 + 1, reserve_price);
+            prop_assert!(!out.is_valid);
+            standing = out.bids;
+        }
+        let (winner, price) = resolve_auction(standing, true, reserve_price);
+        prop_assert_eq!(winner, 0);
+        prop_assert_eq!(price, reserve_price);
+    }
+
+    #[test]
+    fn auction_with_no_bid_at_the_reserve_has_no_winner(
+        reserve_price in 1..=u64::MAX,
+        bids in prop::collection::vec(any::<u64>(), 1..=8),
+        second_price: bool,
+    ) {
+        let low: Vec<u64> = bids.iter().map(|&bid| bid % reserve_price).collect();
+        let (winner, price) =
+            resolve_auction(sealed_auction(&low, reserve_price), second_price, reserve_price);
+        prop_assert_eq!(winner, NO_BID);
+        prop_assert_eq!(price, 0);
+    }
+
+    #[test]
+    fn init_auction_escrows_the_lot(lot_balance: u64, lot_amount: u64) {
+        let out = init_auction(lot_balance, lot_amount);
+        prop_assert_eq!(out.bids, sealed_auction(&[], 0));
+        prop_assert_eq!(out.escrowed, lot_balance >= lot_amount);
+        if out.escrowed {
+            prop_assert_eq!(out.new_lot_balance, lot_balance - lot_amount);
+        } else {
+            prop_assert_eq!(out.new_lot_balance, lot_balance);
+        }
+    }
+
+    #[test]
+    fn submit_bid_escrows_only_covered_bids_at_the_reserve(
+        earlier in prop::collection::vec(any::<u64>(), 0..=4),
+        amount: u64,
+        balance: u64,
+        reserve_price: u64,
+    ) {
+        let standing = sealed_auction(&earlier, reserve_price);
+        let index = earlier.len() as u8;
+        let out = submit_bid(BidInput { amount }, standing, balance, index, reserve_price);
+        prop_assert_eq!(out.is_valid, amount <= balance && amount >= reserve_price);
+        prop_assert_eq!(out.escrow + out.new_balance, balance);
+        if out.is_valid {
+            prop_assert_eq!(out.escrow, amount);
+            let covered = submit_bid(BidInput { amount }, standing, u64::MAX, index, reserve_price);
+            prop_assert_eq!(out.bids, covered.bids);
+        } else {
+            prop_assert_eq!(out.escrow, 0);
+            prop_assert_eq!(out.bids, standing);
+        }
+    }
+
+    #[test]
+    fn sealed_auction_settlement_conserves_both_mints(
+        bids in prop::collection::vec((0..=u32::MAX as u64, 0..=u32::MAX as u64), 1..=8),
+        lot_amount in 0..=u32::MAX as u64,
+        reserve_price in 0..=u32::MAX as u64,
+        second_price: bool,
+    ) {
+        let opened = init_auction(lot_amount, lot_amount);
+        prop_assert!(opened.escrowed);
+
+        let mut standing = opened.bids;
+        let mut escrows = Vec::new();
+        let mut balances = Vec::new();
+        for (i, &(amount, balance)) in bids.iter().enumerate() {
+            let out = submit_bid(BidInput { amount }, standing, balance, i as u8, reserve_price);
+            standing = out.bids;
+            escrows.push(out.escrow);
+            balances.push(out.new_balance);
+        }
+        let (winner, price) = resolve_auction(standing, second_price, reserve_price);
+        let sold = winner != NO_BID;
+
+        let mut bid_total = 0u128;
+        let mut lot_total = 0u128;
+        for (i, (&escrow, &balance)) in escrows.iter().zip(&balances).enumerate() {
+            let won = winner as usize == i;
+            let out = settle_bid(escrow, balance, 0, won, price, lot_amount);
+            prop_assert!(out.settled);
+            bid_total += out.new_bid_balance as u128;
+            lot_total += out.new_lot_balance as u128;
+        }
+        let seller = settle_auction(0, opened.new_lot_balance, sold, price, lot_amount);
+        prop_assert!(seller.settled);
+        bid_total += seller.new_bid_balance as u128;
+        lot_total += seller.new_lot_balance as u128;
+
+        let bid_start: u128 = bids.iter().map(|&(_, balance)| balance as u128).sum();
+        prop_assert_eq!(bid_total, bid_start);
+        prop_assert_eq!(lot_total, lot_amount as u128);
+    }
+
+    #[test]
+    fn settle_bid_refuses_an_uncovered_price(
+        escrow in 0..u64::MAX,
+        bid_balance: u64,
+        lot_balance: u64,
+        lot_amount: u64,
+    ) {
+        let out = settle_bid(escrow, bid_balance, lot_balance, true, escrow + 1, lot_amount);
+        prop_assert!(!out.settled);
+        prop_assert_eq!(out.new_bid_balance, bid_balance);
+        prop_assert_eq!(out.new_lot_balance, lot_balance);
+    }
+
+    #[test]
+    fn disclose_balance_reveals_the_exact_balance(balance: u64) {
+        prop_assert_eq!(disclose_balance(balance), balance);
+    }
+}
@@ -0,0 +1,115 @@
+//! X25519 key exchange and the Arcium Rescue cipher
+//!
+//! `Enc<Shared, T>` circuit inputs are encrypted under a key derived from an
+//! X25519 exchange between the client and the MXE. Every scalar field of `T`
+//! is one base field element and one 32-byte ciphertext, and all fields of a
+//! struct are encrypted together, in declaration order, under one nonce.
+
+use arcis_compiler::{
+    traits::FromLeBytes,
+    utils::{
+        crypto::{
+            key::{X25519PrivateKey, X25519PublicKey},
+            rescue_cipher::RescueCipher,
+        },
+        curve_point::CurvePoint,
+        field::{BaseField, ScalarField},
+    },
+};
+
+/// Errors from [`ClientCipher`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum CipherError {
+    #[error("Invalid X25519 public key")]
+    InvalidPublicKey,
+    #[error("Ciphertext is not a valid field element")]
+    InvalidCiphertext,
+    #[error("Decrypted value is out of range for its field type")]
+    ValueOutOfRange,
+}
+
+/// Cipher shared between a client X25519 key and the MXE
+pub struct ClientCipher {
+    public_key: [u8; 32],
+    cipher: RescueCipher<BaseField, BaseField>,
+}
+
+impl ClientCipher {
+    /// Derive the shared cipher from the client's X25519 secret and the MXE's
+    /// X25519 public key (`MXEAccount::x25519_pubkey`)
+    pub fn new(secret: [u8; 32], mxe_public_key: [u8; 32]) -> Result<Self, CipherError> {
+        let private_key = X25519PrivateKey::<ScalarField>::from_le_bytes(secret);
+        let mxe_public_key = X25519PublicKey::<CurvePoint>::from_le_bytes(mxe_public_key)
+            .ok_or(CipherError::InvalidPublicKey)?;
+
+        Ok(Self {
+            public_key: public_key(secret),
+            cipher: RescueCipher::new_with_client_from_keys(private_key, mxe_public_key),
+        })
+    }
+
+    /// Client X25519 public key, passed to the program as `pubkey`
+    pub fn public_key(&self) -> [u8; 32] {
+        self.public_key
+    }
+
+    /// Encrypt the fields of one circuit input struct
+    ///
+    /// `u64`, `u128` and `bool` fields are all passed as `u128`.
+    pub fn encrypt<const N: usize>(&self, values: [u128; N], nonce: u128) -> [[u8; 32]; N] {
+        let ciphertexts = self
+            .cipher
+            .encrypt(values.map(to_field).to_vec(), to_field(nonce));
+
+        let mut out = [[0u8; 32]; N];
+        for (out, ciphertext) in out.iter_mut().zip(ciphertexts) {
+            *out = ciphertext.to_le_bytes();
+        }
+        out
+    }
+
+    /// Decrypt the fields of one circuit output struct
+    pub fn decrypt<const N: usize>(
+        &self,
+        ciphertexts: &[[u8; 32]; N],
+        nonce: u128,
+    ) -> Result<[u128; N], CipherError> {
+        let ciphertexts = ciphertexts
+            .iter()
+            .map(|c| BaseField::from_le_bytes_checked(*c).ok_or(CipherError::InvalidCiphertext))
+            .collect::<Result<Vec<_>, _>>()?;
+        let plaintexts = self.cipher.decrypt(ciphertexts, to_field(nonce));
+
+        let mut out = [0u128; N];
+        for (out, plaintext) in out.iter_mut().zip(plaintexts) {
+            let bytes = plaintext.to_le_bytes();
+            if bytes[16..].iter().any(|b| *b != 0) {
+                return Err(CipherError::ValueOutOfRange);
+            }
+            *out = u128::from_le_bytes(bytes[..16].try_into().unwrap());
+        }
+        Ok(out)
+    }
+}
+
+/// X25519 public key of `secret`
+pub fn public_key(secret: [u8; 32]) -> [u8; 32] {
+    let private_key = X25519PrivateKey::<ScalarField>::from_le_bytes(secret);
+    X25519PublicKey::<CurvePoint>::new_from_private_key(private_key).to_le_bytes()
+}
+
+/// Fresh X25519 secret
+pub fn random_secret() -> [u8; 32] {
+    rand::random()
+}
+
+/// Fresh encryption nonce, must never be reused with the same key
+pub fn random_nonce() -> u128 {
+    rand::random()
+}
+
+fn to_field(value: u128) -> BaseField {
+    let mut bytes = [0u8; 32];
+    bytes[..16].copy_from_slice(&value.to_le_bytes());
+    BaseField::from_le_bytes(bytes)
+}
@@ -0,0 +1,623 @@
+//! Event decoding and decryption
+//!
+//! Events are read from transaction logs (`Program data: <base64>` lines
+//! emitted while `sip_arcium_transfer` is the executing program), then the
+//! encrypted ones are decrypted into typed results with the user's
+//! [`ClientCipher`].
+//!
+//! Each encrypted event holds one circuit output struct. Its fields were
+//! encrypted together under the event `nonce`, in struct declaration order,
+//! so they must be decrypted together in that same order.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sip_arcium_transfer::{
+    AdminTransferredEvent, AuctionClearedEvent, AuctionFillEvent, BalanceCheckEvent,
+    BalanceCreatedEvent, BalanceDisclosedEvent, BatchTransferEvent, BatchTransferSentViewEvent,
+    ComputationAbortedEvent, ComputationExpiredEvent, ComputationTimeoutUpdatedEvent,
+    CpmmSwapEvent, DepositEvent, FeeSweptEvent, IncomingClaimedEvent, IncomingClaimedViewEvent,
+    LiquidityAddedEvent, LiquidityRemovedEvent, OracleSwapValidationEvent, OrderFilledEvent,
+    OrderPlacedEvent, OrderSettledEvent, OrdersMatchedEvent, PauseUpdatedEvent, PoolCreatedEvent,
+    PriceUpdatedEvent, PrivateTransferEvent, SealedAuctionCreatedEvent, SealedAuctionResolvedEvent,
+    SealedAuctionSettledEvent, SealedBidSettledEvent, SealedBidSubmittedEvent, SwapValidationEvent,
+    TransferReceivedEvent, TransferReceivedViewEvent, TransferSentViewEvent,
+    ViewingKeyUpdatedEvent, WithdrawEvent, BATCH_SIZE, ID,
+};
+
+use crate::cipher::{CipherError, ClientCipher};
+
+/// Any event emitted by `sip_arcium_transfer`
+pub enum SipEvent {
+    BalanceCreated(BalanceCreatedEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    PrivateTransfer(PrivateTransferEvent),
+    TransferReceived(TransferReceivedEvent),
+    BalanceCheck(BalanceCheckEvent),
+    SwapValidation(SwapValidationEvent),
+    ComputationAborted(ComputationAbortedEvent),
+    ComputationExpired(ComputationExpiredEvent),
+    AdminTransferred(AdminTransferredEvent),
+    PauseUpdated(PauseUpdatedEvent),
+    ComputationTimeoutUpdated(ComputationTimeoutUpdatedEvent),
+    BatchTransfer(BatchTransferEvent),
+    PriceUpdated(PriceUpdatedEvent),
+    OracleSwapValidation(OracleSwapValidationEvent),
+    PoolCreated(PoolCreatedEvent),
+    LiquidityAdded(LiquidityAddedEvent),
+    LiquidityRemoved(LiquidityRemovedEvent),
+    CpmmSwap(CpmmSwapEvent),
+    OrderPlaced(OrderPlacedEvent),
+    OrderSettled(OrderSettledEvent),
+    OrdersMatched(OrdersMatchedEvent),
+    OrderFilled(OrderFilledEvent),
+    AuctionCleared(AuctionClearedEvent),
+    AuctionFill(AuctionFillEvent),
+    SealedAuctionCreated(SealedAuctionCreatedEvent),
+    SealedBidSubmitted(SealedBidSubmittedEvent),
+    SealedAuctionResolved(SealedAuctionResolvedEvent),
+    SealedBidSettled(SealedBidSettledEvent),
+    SealedAuctionSettled(SealedAuctionSettledEvent),
+    BalanceDisclosed(BalanceDisclosedEvent),
+    ViewingKeyUpdated(ViewingKeyUpdatedEvent),
+    TransferSentView(TransferSentViewEvent),
+    BatchTransferSentView(BatchTransferSentViewEvent),
+    TransferReceivedView(TransferReceivedViewEvent),
+    IncomingClaimed(IncomingClaimedEvent),
+    IncomingClaimedView(IncomingClaimedViewEvent),
+    FeeSwept(FeeSweptEvent),
+}
+
+impl SipEvent {
+    /// Decode one event from its serialized bytes (discriminator + Borsh)
+    ///
+    /// Returns `None` for data that is not one of this program's events.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        fn decode<T: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<T> {
+            let mut body = data.strip_prefix(T::DISCRIMINATOR)?;
+            T::deserialize(&mut body).ok()
+        }
+
+        decode(data)
+            .map(SipEvent::BalanceCreated)
+            .or_else(|| decode(data).map(SipEvent::Deposit))
+            .or_else(|| decode(data).map(SipEvent::Withdraw))
+            .or_else(|| decode(data).map(SipEvent::PrivateTransfer))
+            .or_else(|| decode(data).map(SipEvent::TransferReceived))
+            .or_else(|| decode(data).map(SipEvent::BalanceCheck))
+            .or_else(|| decode(data).map(SipEvent::SwapValidation))
+            .or_else(|| decode(data).map(SipEvent::ComputationAborted))
+            .or_else(|| decode(data).map(SipEvent::ComputationExpired))
+            .or_else(|| decode(data).map(SipEvent::AdminTransferred))
+            .or_else(|| decode(data).map(SipEvent::PauseUpdated))
+            .or_else(|| decode(data).map(SipEvent::ComputationTimeoutUpdated))
+            .or_else(|| decode(data).map(SipEvent::BatchTransfer))
+            .or_else(|| decode(data).map(SipEvent::PriceUpdated))
+            .or_else(|| decode(data).map(SipEvent::OracleSwapValidation))
+            .or_else(|| decode(data).map(SipEvent::PoolCreated))
+            .or_else(|| decode(data).map(SipEvent::LiquidityAdded))
+            .or_else(|| decode(data).map(SipEvent::LiquidityRemoved))
+            .or_else(|| decode(data).map(SipEvent::CpmmSwap))
+            .or_else(|| decode(data).map(SipEvent::OrderPlaced))
+            .or_else(|| decode(data).map(SipEvent::OrderSettled))
+            .or_else(|| decode(data).map(SipEvent::OrdersMatched))
+            .or_else(|| decode(data).map(SipEvent::OrderFilled))
+            .or_else(|| decode(data).map(SipEvent::AuctionCleared))
+            .or_else(|| decode(data).map(SipEvent::AuctionFill))
+            .or_else(|| decode(data).map(SipEvent::SealedAuctionCreated))
+            .or_else(|| decode(data).map(SipEvent::SealedBidSubmitted))
+            .or_else(|| decode(data).map(SipEvent::SealedAuctionResolved))
+            .or_else(|| decode(data).map(SipEvent::SealedBidSettled))
+            .or_else(|| decode(data).map(SipEvent::SealedAuctionSettled))
+            .or_else(|| decode(data).map(SipEvent::BalanceDisclosed))
+            .or_else(|| decode(data).map(SipEvent::ViewingKeyUpdated))
+            .or_else(|| decode(data).map(SipEvent::TransferSentView))
+            .or_else(|| decode(data).map(SipEvent::BatchTransferSentView))
+            .or_else(|| decode(data).map(SipEvent::TransferReceivedView))
+            .or_else(|| decode(data).map(SipEvent::IncomingClaimed))
+            .or_else(|| decode(data).map(SipEvent::IncomingClaimedView))
+            .or_else(|| decode(data).map(SipEvent::FeeSwept))
+    }
+}
+
+/// Decode every `sip_arcium_transfer` event in a transaction's log messages
+///
+/// Tracks the invocation stack so `Program data:` lines from other programs,
+/// including the Arcium program that invokes the callbacks, are skipped.
+pub fn parse_logs<'a>(logs: impl IntoIterator<Item = &'a str>) -> Vec<SipEvent> {
+    let program_id = ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if stack.last() == Some(&program_id.as_str()) {
+                if let Some(event) = STANDARD
+                    .decode(data)
+                    .ok()
+                    .and_then(|d| SipEvent::decode(&d))
+                {
+                    events.push(event);
+                }
+            }
+        } else if let Some((id, status)) = rest.split_once(' ') {
+            if status.starts_with("invoke [") {
+                stack.push(id);
+            } else if status == "success" || status.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+
+    events
+}
+
+/// An event whose fields are encrypted for a client
+pub trait DecryptEvent {
+    type Output;
+
+    /// Decrypt with the cipher shared between the client key and the MXE
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError>;
+}
+
+/// Outcome of a private transfer, as reported by its `error_code`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    Ok,
+    InsufficientFunds,
+    Overflow,
+    RecipientMismatch,
+    StaleSequence,
+}
+
+impl TryFrom<u8> for TransferStatus {
+    type Error = CipherError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(TransferStatus::Ok),
+            1 => Ok(TransferStatus::InsufficientFunds),
+            2 => Ok(TransferStatus::Overflow),
+            3 => Ok(TransferStatus::RecipientMismatch),
+            4 => Ok(TransferStatus::StaleSequence),
+            _ => Err(CipherError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Decrypted [`PrivateTransferEvent`] or [`BatchTransferEvent`], for the sender
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivateTransferResult {
+    pub is_valid: bool,
+    pub new_sender_balance: u64,
+    pub status: TransferStatus,
+    pub fee: u64,
+}
+
+impl PrivateTransferResult {
+    /// Decrypt the `PrivateTransferOutput` fields shared by both transfer events
+    fn decrypt(
+        cipher: &ClientCipher,
+        ciphertexts: [[u8; 32]; 4],
+        nonce: [u8; 16],
+    ) -> Result<Self, CipherError> {
+        let [is_valid, new_sender_balance, error_code, fee] =
+            cipher.decrypt(&ciphertexts, u128::from_le_bytes(nonce))?;
+
+        Ok(PrivateTransferResult {
+            is_valid: to_bool(is_valid)?,
+            new_sender_balance: to_u64(new_sender_balance)?,
+            status: TransferStatus::try_from(to_u8(error_code)?)?,
+            fee: to_u64(fee)?,
+        })
+    }
+}
+
+impl DecryptEvent for PrivateTransferEvent {
+    type Output = PrivateTransferResult;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        PrivateTransferResult::decrypt(
+            cipher,
+            [
+                self.is_valid,
+                self.new_sender_balance,
+                self.error_code,
+                self.fee,
+            ],
+            self.nonce,
+        )
+    }
+}
+
+impl DecryptEvent for BatchTransferEvent {
+    type Output = PrivateTransferResult;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        PrivateTransferResult::decrypt(
+            cipher,
+            [
+                self.is_valid,
+                self.new_sender_balance,
+                self.error_code,
+                self.fee,
+            ],
+            self.nonce,
+        )
+    }
+}
+
+/// Decrypted [`TransferReceivedEvent`] for the recipient, or
+/// [`TransferReceivedViewEvent`] for their viewing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferReceived {
+    pub amount: u64,
+    pub new_incoming: u64,
+}
+
+impl TransferReceived {
+    fn decrypt(
+        cipher: &ClientCipher,
+        ciphertexts: [[u8; 32]; 2],
+        nonce: [u8; 16],
+    ) -> Result<Self, CipherError> {
+        let [amount, new_incoming] = cipher.decrypt(&ciphertexts, u128::from_le_bytes(nonce))?;
+
+        Ok(TransferReceived {
+            amount: to_u64(amount)?,
+            new_incoming: to_u64(new_incoming)?,
+        })
+    }
+}
+
+impl DecryptEvent for TransferReceivedEvent {
+    type Output = TransferReceived;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        TransferReceived::decrypt(cipher, [self.amount, self.new_incoming], self.nonce)
+    }
+}
+
+impl DecryptEvent for BalanceCheckEvent {
+    /// Whether the balance meets the minimum
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [meets_minimum] =
+            cipher.decrypt(&[self.meets_minimum], u128::from_le_bytes(self.nonce))?;
+        to_bool(meets_minimum)
+    }
+}
+
+/// Decrypted [`SwapValidationEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapValidationResult {
+    pub is_valid: bool,
+    pub new_input_balance: u64,
+    pub slippage_ok: bool,
+    pub new_output_balance: u64,
+}
+
+impl DecryptEvent for SwapValidationEvent {
+    type Output = SwapValidationResult;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid, new_input_balance, slippage_ok, new_output_balance] = cipher.decrypt(
+            &[
+                self.is_valid,
+                self.new_input_balance,
+                self.slippage_ok,
+                self.new_output_balance,
+            ],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(SwapValidationResult {
+            is_valid: to_bool(is_valid)?,
+            new_input_balance: to_u64(new_input_balance)?,
+            slippage_ok: to_bool(slippage_ok)?,
+            new_output_balance: to_u64(new_output_balance)?,
+        })
+    }
+}
+
+/// Decrypted [`OracleSwapValidationEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OracleSwapValidationResult {
+    pub is_valid: bool,
+    pub slippage_ok: bool,
+    pub expected_output: u64,
+}
+
+impl DecryptEvent for OracleSwapValidationEvent {
+    type Output = OracleSwapValidationResult;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid, slippage_ok, expected_output] = cipher.decrypt(
+            &[self.is_valid, self.slippage_ok, self.expected_output],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(OracleSwapValidationResult {
+            is_valid: to_bool(is_valid)?,
+            slippage_ok: to_bool(slippage_ok)?,
+            expected_output: to_u64(expected_output)?,
+        })
+    }
+}
+
+impl DecryptEvent for LiquidityAddedEvent {
+    /// Whether both amounts were added
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid] = cipher.decrypt(&[self.is_valid], u128::from_le_bytes(self.nonce))?;
+        to_bool(is_valid)
+    }
+}
+
+impl DecryptEvent for LiquidityRemovedEvent {
+    /// Whether both amounts were removed
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid] = cipher.decrypt(&[self.is_valid], u128::from_le_bytes(self.nonce))?;
+        to_bool(is_valid)
+    }
+}
+
+/// Decrypted [`CpmmSwapEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpmmSwapResult {
+    pub is_valid: bool,
+    pub amount_out: u64,
+    pub fee: u64,
+}
+
+impl DecryptEvent for CpmmSwapEvent {
+    type Output = CpmmSwapResult;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid, amount_out, fee] = cipher.decrypt(
+            &[self.is_valid, self.amount_out, self.fee],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(CpmmSwapResult {
+            is_valid: to_bool(is_valid)?,
+            amount_out: to_u64(amount_out)?,
+            fee: to_u64(fee)?,
+        })
+    }
+}
+
+impl DecryptEvent for OrderPlacedEvent {
+    /// Whether the balance covered the order's escrow
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid] = cipher.decrypt(&[self.is_valid], u128::from_le_bytes(self.nonce))?;
+        to_bool(is_valid)
+    }
+}
+
+impl DecryptEvent for OrdersMatchedEvent {
+    /// Whether the orders crossed, for the matcher
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [matched] = cipher.decrypt(&[self.matched], u128::from_le_bytes(self.nonce))?;
+        to_bool(matched)
+    }
+}
+
+/// Decrypted [`OrderFilledEvent`], for the order's owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderFill {
+    pub filled: u64,
+    pub remaining: u64,
+    pub proceeds: u64,
+}
+
+impl DecryptEvent for OrderFilledEvent {
+    type Output = OrderFill;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [filled, remaining, proceeds] = cipher.decrypt(
+            &[self.filled, self.remaining, self.proceeds],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(OrderFill {
+            filled: to_u64(filled)?,
+            remaining: to_u64(remaining)?,
+            proceeds: to_u64(proceeds)?,
+        })
+    }
+}
+
+impl DecryptEvent for AuctionClearedEvent {
+    /// Whether any volume crossed, for the matcher
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [cleared] = cipher.decrypt(&[self.cleared], u128::from_le_bytes(self.nonce))?;
+        to_bool(cleared)
+    }
+}
+
+/// Decrypted [`AuctionFillEvent`], for the order's owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuctionFill {
+    pub filled: u64,
+    pub clearing_price: u64,
+    pub remaining: u64,
+    pub proceeds: u64,
+}
+
+impl DecryptEvent for AuctionFillEvent {
+    type Output = AuctionFill;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [filled, clearing_price, remaining, proceeds] = cipher.decrypt(
+            &[
+                self.filled,
+                self.clearing_price,
+                self.remaining,
+                self.proceeds,
+            ],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(AuctionFill {
+            filled: to_u64(filled)?,
+            clearing_price: to_u64(clearing_price)?,
+            remaining: to_u64(remaining)?,
+            proceeds: to_u64(proceeds)?,
+        })
+    }
+}
+
+impl DecryptEvent for SealedBidSubmittedEvent {
+    /// Whether the bidder's balance covered the bid, for the bidder
+    type Output = bool;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [is_valid] = cipher.decrypt(&[self.is_valid], u128::from_le_bytes(self.nonce))?;
+        to_bool(is_valid)
+    }
+}
+
+impl DecryptEvent for BalanceDisclosedEvent {
+    /// The disclosed balance, for the auditor
+    type Output = u64;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [balance] =
+            cipher.decrypt(&[self.encrypted_balance], u128::from_le_bytes(self.nonce))?;
+        to_u64(balance)
+    }
+}
+
+/// Decrypted [`TransferSentViewEvent`], for the sender's viewing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferSentView {
+    pub amount: u64,
+    pub fee: u64,
+    pub new_sender_balance: u64,
+}
+
+impl DecryptEvent for TransferSentViewEvent {
+    type Output = TransferSentView;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let [amount, fee, new_sender_balance] = cipher.decrypt(
+            &[self.amount, self.fee, self.new_sender_balance],
+            u128::from_le_bytes(self.nonce),
+        )?;
+
+        Ok(TransferSentView {
+            amount: to_u64(amount)?,
+            fee: to_u64(fee)?,
+            new_sender_balance: to_u64(new_sender_balance)?,
+        })
+    }
+}
+
+/// Decrypted [`BatchTransferSentViewEvent`], for the sender's viewing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchTransferSentView {
+    /// Amount sent per slot, in the event's `recipients` order
+    pub amounts: [u64; BATCH_SIZE],
+    pub fee: u64,
+    pub new_sender_balance: u64,
+}
+
+impl DecryptEvent for BatchTransferSentViewEvent {
+    type Output = BatchTransferSentView;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        let mut ciphertexts = [[0u8; 32]; BATCH_SIZE + 2];
+        ciphertexts[..BATCH_SIZE].copy_from_slice(&self.amounts);
+        ciphertexts[BATCH_SIZE] = self.fee;
+        ciphertexts[BATCH_SIZE + 1] = self.new_sender_balance;
+        let values = cipher.decrypt(&ciphertexts, u128::from_le_bytes(self.nonce))?;
+
+        let mut amounts = [0u64; BATCH_SIZE];
+        for (amount, value) in amounts.iter_mut().zip(values) {
+            *amount = to_u64(value)?;
+        }
+        Ok(BatchTransferSentView {
+            amounts,
+            fee: to_u64(values[BATCH_SIZE])?,
+            new_sender_balance: to_u64(values[BATCH_SIZE + 1])?,
+        })
+    }
+}
+
+impl DecryptEvent for TransferReceivedViewEvent {
+    type Output = TransferReceived;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        TransferReceived::decrypt(cipher, [self.amount, self.new_incoming], self.nonce)
+    }
+}
+
+/// Decrypted [`IncomingClaimedEvent`] for the owner, or
+/// [`IncomingClaimedViewEvent`] for their viewing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncomingClaim {
+    pub claimed: u64,
+    pub new_balance: u64,
+}
+
+impl IncomingClaim {
+    fn decrypt(
+        cipher: &ClientCipher,
+        ciphertexts: [[u8; 32]; 2],
+        nonce: [u8; 16],
+    ) -> Result<Self, CipherError> {
+        let [claimed, new_balance] = cipher.decrypt(&ciphertexts, u128::from_le_bytes(nonce))?;
+
+        Ok(IncomingClaim {
+            claimed: to_u64(claimed)?,
+            new_balance: to_u64(new_balance)?,
+        })
+    }
+}
+
+impl DecryptEvent for IncomingClaimedEvent {
+    type Output = IncomingClaim;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        IncomingClaim::decrypt(cipher, [self.claimed, self.new_balance], self.nonce)
+    }
+}
+
+impl DecryptEvent for IncomingClaimedViewEvent {
+    type Output = IncomingClaim;
+
+    fn decrypt(&self, cipher: &ClientCipher) -> Result<Self::Output, CipherError> {
+        IncomingClaim::decrypt(cipher, [self.claimed, self.new_balance], self.nonce)
+    }
+}
+
+fn to_bool(value: u128) -> Result<bool, CipherError> {
+    match value {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(CipherError::ValueOutOfRange),
+    }
+}
+
+fn to_u64(value: u128) -> Result<u64, CipherError> {
+    u64::try_from(value).map_err(|_| CipherError::ValueOutOfRange)
+}
+
+fn to_u8(value: u128) -> Result<u8, CipherError> {
+    u8::try_from(value).map_err(|_| CipherError::ValueOutOfRange)
+}
@@ -0,0 +1,1385 @@
+//! Instruction builders for `sip_arcium_transfer`
+//!
+//! Each builder derives the program and Arcium accounts, encrypts the circuit
+//! input with a fresh nonce and returns an [`Instruction`] for the payer to sign.
+
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::instruction::{AccountMeta, Instruction},
+    system_program, InstructionData, ToAccountMetas,
+};
+use arcium_anchor::{ARCIUM_CLOCK_ACCOUNT_ADDRESS, ARCIUM_FEE_POOL_ACCOUNT_ADDRESS};
+use arcium_client::ARCIUM_PROGRAM_ID;
+use sip_arcium_transfer::{
+    accounts, instruction, pubkey_to_limbs, AUCTION_SIZE, BATCH_SIZE,
+    COMP_DEF_OFFSET_ADD_LIQUIDITY, COMP_DEF_OFFSET_BATCH_AUCTION, COMP_DEF_OFFSET_BATCH_TRANSFER,
+    COMP_DEF_OFFSET_CHECK_BALANCE, COMP_DEF_OFFSET_CLAIM_INCOMING, COMP_DEF_OFFSET_CREATE_BALANCE,
+    COMP_DEF_OFFSET_DEPOSIT, COMP_DEF_OFFSET_DISCLOSE_BALANCE, COMP_DEF_OFFSET_INIT_AUCTION,
+    COMP_DEF_OFFSET_INIT_POOL, COMP_DEF_OFFSET_MATCH_ORDERS, COMP_DEF_OFFSET_PLACE_ORDER,
+    COMP_DEF_OFFSET_PRIVATE_TRANSFER, COMP_DEF_OFFSET_REMOVE_LIQUIDITY,
+    COMP_DEF_OFFSET_RESOLVE_AUCTION, COMP_DEF_OFFSET_SETTLE_AUCTION, COMP_DEF_OFFSET_SETTLE_BID,
+    COMP_DEF_OFFSET_SETTLE_ORDER, COMP_DEF_OFFSET_SUBMIT_BID, COMP_DEF_OFFSET_SWAP_CPMM,
+    COMP_DEF_OFFSET_SWEEP_FEE, COMP_DEF_OFFSET_VALIDATE_ORACLE_SWAP, COMP_DEF_OFFSET_VALIDATE_SWAP,
+    COMP_DEF_OFFSET_WITHDRAW, ID,
+};
+
+use crate::{
+    cipher::{random_nonce, ClientCipher},
+    pda,
+};
+
+/// Builds instructions for a single payer
+///
+/// The payer's [`ClientCipher`] encrypts every `Enc<Shared, _>` input, and its
+/// public key receives the encrypted results.
+pub struct InstructionBuilder {
+    payer: Pubkey,
+    cluster_offset: u32,
+    cipher: ClientCipher,
+}
+
+/// Accounts shared by every instruction that queues a computation, in program order
+struct ArciumAccounts {
+    computation_request: Pubkey,
+    sign_pda_account: Pubkey,
+    mxe_account: Pubkey,
+    mempool_account: Pubkey,
+    executing_pool: Pubkey,
+    computation_account: Pubkey,
+    comp_def_account: Pubkey,
+    cluster_account: Pubkey,
+}
+
+impl InstructionBuilder {
+    /// `cluster_offset` is the cluster the program's MXE is deployed to
+    pub fn new(payer: Pubkey, cluster_offset: u32, cipher: ClientCipher) -> Self {
+        Self {
+            payer,
+            cluster_offset,
+            cipher,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    pub fn cipher(&self) -> &ClientCipher {
+        &self.cipher
+    }
+
+    /// Create the global config with the payer as admin
+    ///
+    /// The payer must be the program's upgrade authority.
+    pub fn initialize_config(&self) -> Instruction {
+        let accounts = accounts::InitializeConfig {
+            payer: self.payer,
+            config: pda::config_pda(),
+            program: ID,
+            program_data: pda::program_data_pda(),
+            system_program: system_program::ID,
+        };
+
+        build(accounts, instruction::InitializeConfig {})
+    }
+
+    /// Propose `new_admin` as the next admin (payer must be the admin)
+    pub fn propose_admin(&self, new_admin: &Pubkey) -> Instruction {
+        let accounts = accounts::ProposeAdmin {
+            admin: self.payer,
+            config: pda::config_pda(),
+        };
+
+        build(
+            accounts,
+            instruction::ProposeAdmin {
+                new_admin: *new_admin,
+            },
+        )
+    }
+
+    /// Pause or resume queueing new computations (payer must be the admin)
+    pub fn set_paused(&self, paused: bool) -> Instruction {
+        let accounts = accounts::SetPaused {
+            admin: self.payer,
+            config: pda::config_pda(),
+        };
+
+        build(accounts, instruction::SetPaused { paused })
+    }
+
+    /// Set the slots after queueing before a computation may be reclaimed
+    /// (payer must be the admin)
+    pub fn set_computation_timeout(&self, timeout_slots: u64) -> Instruction {
+        let accounts = accounts::SetComputationTimeout {
+            admin: self.payer,
+            config: pda::config_pda(),
+        };
+
+        build(
+            accounts,
+            instruction::SetComputationTimeout { timeout_slots },
+        )
+    }
+
+    /// Accept an admin proposal naming the payer
+    pub fn accept_admin(&self) -> Instruction {
+        let accounts = accounts::AcceptAdmin {
+            pending_admin: self.payer,
+            config: pda::config_pda(),
+        };
+
+        build(accounts, instruction::AcceptAdmin {})
+    }
+
+    /// Create the token vault for `mint`
+    pub fn init_vault(&self, mint: &Pubkey) -> Instruction {
+        let accounts = accounts::InitVault {
+            payer: self.payer,
+            mint: *mint,
+            vault: pda::vault_pda(mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        };
+
+        build(accounts, instruction::InitVault {})
+    }
+
+    /// Create the fee treasury for `mint` (payer must be the admin)
+    ///
+    /// Fees are credited to `authority`'s encrypted balance for `mint`.
+    pub fn init_treasury(&self, mint: &Pubkey, fee_bps: u16, authority: &Pubkey) -> Instruction {
+        let accounts = accounts::InitTreasury {
+            payer: self.payer,
+            config: pda::config_pda(),
+            mint: *mint,
+            treasury: pda::treasury_pda(mint),
+            system_program: system_program::ID,
+        };
+
+        build(
+            accounts,
+            instruction::InitTreasury {
+                fee_bps,
+                authority: *authority,
+            },
+        )
+    }
+
+    /// Change the fee rate of `mint`'s treasury (payer must be the admin)
+    pub fn set_treasury_fee(&self, mint: &Pubkey, fee_bps: u16) -> Instruction {
+        let accounts = accounts::SetTreasuryFee {
+            admin: self.payer,
+            config: pda::config_pda(),
+            treasury: pda::treasury_pda(mint),
+        };
+
+        build(accounts, instruction::SetTreasuryFee { fee_bps })
+    }
+
+    /// Create the `input_mint` → `output_mint` price feed (payer must be the admin)
+    ///
+    /// Only `authority` can publish prices to it.
+    pub fn init_price_feed(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        authority: &Pubkey,
+    ) -> Instruction {
+        let accounts = accounts::InitPriceFeed {
+            payer: self.payer,
+            config: pda::config_pda(),
+            input_mint: *input_mint,
+            output_mint: *output_mint,
+            price_feed: pda::price_feed_pda(input_mint, output_mint),
+            system_program: system_program::ID,
+        };
+
+        build(
+            accounts,
+            instruction::InitPriceFeed {
+                authority: *authority,
+            },
+        )
+    }
+
+    /// Publish `price` (scaled by `PRICE_SCALE`) to a price feed (payer must be its authority)
+    pub fn update_price(
+        &self,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        price: u64,
+    ) -> Instruction {
+        let accounts = accounts::UpdatePrice {
+            authority: self.payer,
+            price_feed: pda::price_feed_pda(input_mint, output_mint),
+        };
+
+        build(accounts, instruction::UpdatePrice { price })
+    }
+
+    /// Create the payer's encrypted balance for `mint`
+    ///
+    /// Incoming transfer receipts are encrypted to this builder's public key.
+    pub fn create_balance(&self, computation_offset: u64, mint: &Pubkey) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_CREATE_BALANCE);
+        let accounts = accounts::CreateBalance {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            mint: *mint,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::CreateBalance {
+                computation_offset,
+                encryption_pubkey: self.cipher.public_key(),
+            },
+        )
+    }
+
+    /// Deposit `amount` from `payer_token_account` into the payer's encrypted balance
+    pub fn deposit(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        payer_token_account: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_DEPOSIT);
+        let accounts = accounts::Deposit {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            mint: *mint,
+            vault: pda::vault_pda(mint),
+            payer_token_account: *payer_token_account,
+            token_program: anchor_spl::token::ID,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::Deposit {
+                computation_offset,
+                amount,
+            },
+        )
+    }
+
+    /// Withdraw `amount` from the payer's encrypted balance to `destination`
+    pub fn withdraw(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_WITHDRAW);
+        let accounts = accounts::Withdraw {
+            payer: self.payer,
+            balance_account: pda::balance_pda(mint, &self.payer),
+            mint: *mint,
+            vault: pda::vault_pda(mint),
+            destination: *destination,
+            token_program: anchor_spl::token::ID,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::Withdraw {
+                computation_offset,
+                amount,
+            },
+        )
+    }
+
+    /// Sweep the fee in `fee_record` into the payer's `mint` balance
+    ///
+    /// The payer must be the mint's `Treasury::authority`. The record's rent
+    /// goes back to the sender who paid it once the fee is swept.
+    pub fn sweep_fee(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        fee_record: &Pubkey,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SWEEP_FEE);
+        let accounts = accounts::SweepFee {
+            payer: self.payer,
+            treasury: pda::treasury_pda(mint),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            fee_record: *fee_record,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(accounts, instruction::SweepFee { computation_offset })
+    }
+
+    /// Move everything transferred to the payer's `mint` balance into its
+    /// spendable balance
+    pub fn claim_incoming(&self, computation_offset: u64, mint: &Pubkey) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_CLAIM_INCOMING);
+        let accounts = accounts::ClaimIncoming {
+            payer: self.payer,
+            balance_account: pda::balance_pda(mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(accounts, instruction::ClaimIncoming { computation_offset })
+    }
+
+    /// Transfer `amount` of `mint` to `recipient`, keeping at least `min_balance`
+    ///
+    /// The recipient must already have an encrypted balance for `mint`; the
+    /// amount lands in its incoming balance until the recipient calls
+    /// [`Self::claim_incoming`]. `sequence` is the payer's current `EncryptedBalanceAccount::sequence`;
+    /// the transfer is rejected if the balance is written before it runs.
+    /// `charges_fee` says whether the mint has a `Treasury` with a non-zero
+    /// `fee_bps`; if so a [`pda::fee_record_pda`] is created for the fee.
+    #[allow(clippy::too_many_arguments)]
+    pub fn private_transfer(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        amount: u64,
+        min_balance: u64,
+        sequence: u64,
+        charges_fee: bool,
+    ) -> Instruction {
+        let (recipient_lo, recipient_hi) = pubkey_to_limbs(recipient);
+        let nonce = random_nonce();
+        let [encrypted_amount, encrypted_min_balance, recipient_lo, recipient_hi, encrypted_sequence] =
+            self.cipher.encrypt(
+                [
+                    amount as u128,
+                    min_balance as u128,
+                    recipient_lo,
+                    recipient_hi,
+                    sequence as u128,
+                ],
+                nonce,
+            );
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_PRIVATE_TRANSFER);
+        let accounts = accounts::PrivateTransfer {
+            payer: self.payer,
+            config: pda::config_pda(),
+            sender_balance: pda::balance_pda(mint, &self.payer),
+            recipient_balance: pda::balance_pda(mint, recipient),
+            treasury: pda::treasury_pda(mint),
+            fee_record: charges_fee.then(|| pda::fee_record_pda(computation_offset)),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::PrivateTransfer {
+                computation_offset,
+                encrypted_amount,
+                encrypted_min_balance,
+                encrypted_recipient: [recipient_lo, recipient_hi],
+                encrypted_sequence,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Pay each `(recipient, amount)` in `transfers` from one encrypted balance
+    ///
+    /// The batch always fills `BATCH_SIZE` slots: `decoys` names owners of
+    /// other `mint` balances that fill the remaining slots with an encrypted
+    /// zero, so `transfers.len() + decoys.len()` must equal `BATCH_SIZE`.
+    /// Every slot's balance must be distinct and must not be the payer's.
+    /// `sequence` and `charges_fee` are as for [`Self::private_transfer`];
+    /// the whole batch is applied or rejected.
+    ///
+    /// # Panics
+    /// If `transfers` and `decoys` do not fill exactly `BATCH_SIZE` slots.
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_transfer(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        transfers: &[(Pubkey, u64)],
+        decoys: &[Pubkey],
+        min_balance: u64,
+        sequence: u64,
+        charges_fee: bool,
+    ) -> Instruction {
+        assert_eq!(
+            transfers.len() + decoys.len(),
+            BATCH_SIZE,
+            "batch_transfer needs exactly BATCH_SIZE slots"
+        );
+        let slots: Vec<(Pubkey, u64)> = transfers
+            .iter()
+            .copied()
+            .chain(decoys.iter().map(|decoy| (*decoy, 0)))
+            .collect();
+
+        // BatchTransferInput field order: amounts, recipient low limbs,
+        // recipient high limbs, min_balance, sequence
+        let mut values = [0u128; 3 * BATCH_SIZE + 2];
+        for (i, (recipient, amount)) in slots.iter().enumerate() {
+            let (recipient_lo, recipient_hi) = pubkey_to_limbs(recipient);
+            values[i] = *amount as u128;
+            values[BATCH_SIZE + i] = recipient_lo;
+            values[2 * BATCH_SIZE + i] = recipient_hi;
+        }
+        values[3 * BATCH_SIZE] = min_balance as u128;
+        values[3 * BATCH_SIZE + 1] = sequence as u128;
+
+        let nonce = random_nonce();
+        let ciphertexts = self.cipher.encrypt(values, nonce);
+        let encrypted_amounts: [[u8; 32]; BATCH_SIZE] = std::array::from_fn(|i| ciphertexts[i]);
+        let encrypted_recipients: [[[u8; 32]; 2]; BATCH_SIZE] =
+            std::array::from_fn(|i| [ciphertexts[BATCH_SIZE + i], ciphertexts[2 * BATCH_SIZE + i]]);
+        let recipient_balances: [Pubkey; BATCH_SIZE] =
+            std::array::from_fn(|i| pda::balance_pda(mint, &slots[i].0));
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_BATCH_TRANSFER);
+        let accounts = accounts::BatchTransfer {
+            payer: self.payer,
+            config: pda::config_pda(),
+            sender_balance: pda::balance_pda(mint, &self.payer),
+            recipient_balance_0: recipient_balances[0],
+            recipient_balance_1: recipient_balances[1],
+            recipient_balance_2: recipient_balances[2],
+            recipient_balance_3: recipient_balances[3],
+            treasury: pda::treasury_pda(mint),
+            fee_record: charges_fee.then(|| pda::fee_record_pda(computation_offset)),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::BatchTransfer {
+                computation_offset,
+                encrypted_amounts,
+                encrypted_recipients,
+                encrypted_min_balance: ciphertexts[3 * BATCH_SIZE],
+                encrypted_sequence: ciphertexts[3 * BATCH_SIZE + 1],
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Check that the payer's encrypted balance for `mint` is at least `minimum`
+    pub fn check_balance(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        minimum: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_minimum] = self.cipher.encrypt([minimum as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_CHECK_BALANCE);
+        let accounts = accounts::CheckBalance {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::CheckBalance {
+                computation_offset,
+                encrypted_minimum,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Validate a swap of `input_amount` of `mint` into `actual_output` of `output_mint`
+    ///
+    /// The payer needs an encrypted balance for both mints.
+    pub fn validate_swap(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        output_mint: &Pubkey,
+        input_amount: u64,
+        min_output: u64,
+        actual_output: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_input_amount, encrypted_min_output, encrypted_actual_output] =
+            self.cipher.encrypt(
+                [
+                    input_amount as u128,
+                    min_output as u128,
+                    actual_output as u128,
+                ],
+                nonce,
+            );
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_VALIDATE_SWAP);
+        let accounts = accounts::ValidateSwap {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            output_balance_account: pda::balance_pda(output_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::ValidateSwap {
+                computation_offset,
+                encrypted_input_amount,
+                encrypted_min_output,
+                encrypted_actual_output,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Validate a swap of `input_amount` of `mint` into `output_mint` at the oracle price
+    ///
+    /// The MXE computes the output from the pair's `PriceFeed`, which must
+    /// be fresh, and checks it against `min_output`. This is a quote only;
+    /// neither balance changes.
+    pub fn validate_oracle_swap(
+        &self,
+        computation_offset: u64,
+        mint: &Pubkey,
+        output_mint: &Pubkey,
+        input_amount: u64,
+        min_output: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_input_amount, encrypted_min_output] = self
+            .cipher
+            .encrypt([input_amount as u128, min_output as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_VALIDATE_ORACLE_SWAP);
+        let accounts = accounts::ValidateOracleSwap {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            output_balance_account: pda::balance_pda(output_mint, &self.payer),
+            price_feed: pda::price_feed_pda(mint, output_mint),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::ValidateOracleSwap {
+                computation_offset,
+                encrypted_input_amount,
+                encrypted_min_output,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Create the constant-product pool for a mint pair (payer must be the admin)
+    ///
+    /// The mints may be given in either order. Swaps pay `fee_bps` of their
+    /// input into the pool.
+    pub fn init_pool(
+        &self,
+        computation_offset: u64,
+        mint_x: &Pubkey,
+        mint_y: &Pubkey,
+        fee_bps: u16,
+    ) -> Instruction {
+        let (mint_a, mint_b) = pda::pool_mints(mint_x, mint_y);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_INIT_POOL);
+        let accounts = accounts::InitPool {
+            payer: self.payer,
+            config: pda::config_pda(),
+            mint_a: *mint_a,
+            mint_b: *mint_b,
+            pool: pda::pool_pda(mint_a, mint_b),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::InitPool {
+                computation_offset,
+                fee_bps,
+            },
+        )
+    }
+
+    /// Move `amount_a` of `mint_a` and `amount_b` of `mint_b` from the
+    /// payer's encrypted balances into a pool (payer must be the admin)
+    ///
+    /// `mint_a` and `mint_b` are in pool order, see [`pda::pool_mints`].
+    pub fn add_liquidity(
+        &self,
+        computation_offset: u64,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
+        amount_a: u64,
+        amount_b: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_amount_a, encrypted_amount_b] = self
+            .cipher
+            .encrypt([amount_a as u128, amount_b as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_ADD_LIQUIDITY);
+        let accounts = accounts::AddLiquidity {
+            payer: self.payer,
+            config: pda::config_pda(),
+            pool: pda::pool_pda(mint_a, mint_b),
+            provider_balance_a: pda::balance_pda(mint_a, &self.payer),
+            provider_balance_b: pda::balance_pda(mint_b, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::AddLiquidity {
+                computation_offset,
+                encrypted_amount_a,
+                encrypted_amount_b,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Move `amount_a` of `mint_a` and `amount_b` of `mint_b` out of a pool
+    /// into the payer's encrypted balances (payer must be the admin)
+    ///
+    /// `mint_a` and `mint_b` are in pool order, see [`pda::pool_mints`].
+    pub fn remove_liquidity(
+        &self,
+        computation_offset: u64,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
+        amount_a: u64,
+        amount_b: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_amount_a, encrypted_amount_b] = self
+            .cipher
+            .encrypt([amount_a as u128, amount_b as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_REMOVE_LIQUIDITY);
+        let accounts = accounts::RemoveLiquidity {
+            payer: self.payer,
+            config: pda::config_pda(),
+            pool: pda::pool_pda(mint_a, mint_b),
+            provider_balance_a: pda::balance_pda(mint_a, &self.payer),
+            provider_balance_b: pda::balance_pda(mint_b, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::RemoveLiquidity {
+                computation_offset,
+                encrypted_amount_a,
+                encrypted_amount_b,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Swap `amount_in` of `input_mint` for at least `min_amount_out` of
+    /// `output_mint` against their pool
+    ///
+    /// The payer needs an encrypted balance for both mints. The direction is
+    /// encrypted along with the amounts.
+    pub fn swap_cpmm(
+        &self,
+        computation_offset: u64,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Instruction {
+        let (mint_a, mint_b) = pda::pool_mints(input_mint, output_mint);
+        let a_to_b = mint_a == input_mint;
+
+        let nonce = random_nonce();
+        let [encrypted_amount_in, encrypted_min_amount_out, encrypted_a_to_b] =
+            self.cipher.encrypt(
+                [amount_in as u128, min_amount_out as u128, a_to_b as u128],
+                nonce,
+            );
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SWAP_CPMM);
+        let accounts = accounts::SwapCpmm {
+            payer: self.payer,
+            config: pda::config_pda(),
+            pool: pda::pool_pda(mint_a, mint_b),
+            trader_balance_a: pda::balance_pda(mint_a, &self.payer),
+            trader_balance_b: pda::balance_pda(mint_b, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::SwapCpmm {
+                computation_offset,
+                encrypted_amount_in,
+                encrypted_min_amount_out,
+                encrypted_a_to_b,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Place a limit order to buy (`is_buy`) or sell `size` of `base_mint`
+    /// at `price` (`quote_mint` per base unit, scaled by `PRICE_SCALE`)
+    ///
+    /// The payer needs an encrypted balance for both mints; the order's
+    /// escrow is taken from one of them. Only `matcher` may match the order.
+    /// Fill notices are encrypted to this builder's public key.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_order(
+        &self,
+        computation_offset: u64,
+        order_id: u64,
+        base_mint: &Pubkey,
+        quote_mint: &Pubkey,
+        is_buy: bool,
+        price: u64,
+        size: u64,
+        matcher: &Pubkey,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_is_buy, encrypted_price, encrypted_size] = self
+            .cipher
+            .encrypt([is_buy as u128, price as u128, size as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_PLACE_ORDER);
+        let accounts = accounts::PlaceOrder {
+            payer: self.payer,
+            config: pda::config_pda(),
+            base_mint: *base_mint,
+            quote_mint: *quote_mint,
+            order: pda::order_pda(&self.payer, order_id),
+            base_balance: pda::balance_pda(base_mint, &self.payer),
+            quote_balance: pda::balance_pda(quote_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::PlaceOrder {
+                computation_offset,
+                order_id,
+                encrypted_is_buy,
+                encrypted_price,
+                encrypted_size,
+                pubkey: self.cipher.public_key(),
+                nonce,
+                matcher: *matcher,
+            },
+        )
+    }
+
+    /// Settle the payer's order `order_id` on `base_mint`/`quote_mint` into
+    /// their encrypted balances
+    ///
+    /// With `close`, the unfilled escrow is refunded as well and the order is
+    /// closed, cancelling it.
+    pub fn settle_order(
+        &self,
+        computation_offset: u64,
+        order_id: u64,
+        base_mint: &Pubkey,
+        quote_mint: &Pubkey,
+        close: bool,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SETTLE_ORDER);
+        let accounts = accounts::SettleOrder {
+            owner: self.payer,
+            order: pda::order_pda(&self.payer, order_id),
+            base_balance: pda::balance_pda(base_mint, &self.payer),
+            quote_balance: pda::balance_pda(quote_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::SettleOrder {
+                computation_offset,
+                close,
+            },
+        )
+    }
+
+    /// Match two resting orders on the same pair, see [`pda::order_pda`]
+    ///
+    /// Both orders must name the payer as their matcher. Whether they crossed
+    /// is encrypted to this builder's public key.
+    pub fn match_orders(
+        &self,
+        computation_offset: u64,
+        order_a: &Pubkey,
+        order_b: &Pubkey,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_MATCH_ORDERS);
+        let accounts = accounts::MatchOrders {
+            payer: self.payer,
+            config: pda::config_pda(),
+            order_a: *order_a,
+            order_b: *order_b,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::MatchOrders {
+                computation_offset,
+                pubkey: self.cipher.public_key(),
+            },
+        )
+    }
+
+    /// Clear up to [`AUCTION_SIZE`] resting orders on the same pair at one
+    /// price, see [`pda::order_pda`]
+    ///
+    /// Every order must name the payer as its matcher. Whether the batch
+    /// cleared is encrypted to this builder's public key.
+    pub fn batch_auction(
+        &self,
+        computation_offset: u64,
+        orders: &[Pubkey; AUCTION_SIZE],
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_BATCH_AUCTION);
+        let accounts = accounts::BatchAuction {
+            payer: self.payer,
+            config: pda::config_pda(),
+            order_0: orders[0],
+            order_1: orders[1],
+            order_2: orders[2],
+            order_3: orders[3],
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::BatchAuction {
+                computation_offset,
+                pubkey: self.cipher.public_key(),
+            },
+        )
+    }
+
+    /// Open a sealed-bid auction selling `lot_amount` of `lot_mint` for
+    /// `bid_mint`, with the payer as seller
+    ///
+    /// The lot is escrowed from the payer's `lot_mint` balance. Bids are
+    /// accepted until `end_slot`; with `second_price` the winner pays the
+    /// second-highest bid. Bids below `reserve_price` never win, and the lot
+    /// never clears below it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_auction(
+        &self,
+        computation_offset: u64,
+        auction_id: u64,
+        lot_mint: &Pubkey,
+        bid_mint: &Pubkey,
+        second_price: bool,
+        lot_amount: u64,
+        end_slot: u64,
+        reserve_price: u64,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_INIT_AUCTION);
+        let accounts = accounts::CreateAuction {
+            payer: self.payer,
+            config: pda::config_pda(),
+            lot_mint: *lot_mint,
+            bid_mint: *bid_mint,
+            auction: pda::auction_pda(&self.payer, auction_id),
+            seller_lot_balance: pda::balance_pda(lot_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::CreateAuction {
+                computation_offset,
+                auction_id,
+                second_price,
+                lot_amount,
+                end_slot,
+                reserve_price,
+            },
+        )
+    }
+
+    /// Place a sealed bid of `amount` on an auction, see [`pda::auction_pda`]
+    ///
+    /// The bid is escrowed from the payer's `bid_mint` balance. Whether it was
+    /// covered is encrypted to this builder's public key.
+    pub fn submit_bid(
+        &self,
+        computation_offset: u64,
+        auction: &Pubkey,
+        bid_mint: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let nonce = random_nonce();
+        let [encrypted_amount] = self.cipher.encrypt([amount as u128], nonce);
+
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SUBMIT_BID);
+        let accounts = accounts::SubmitBid {
+            payer: self.payer,
+            config: pda::config_pda(),
+            auction: *auction,
+            bid: pda::bid_pda(auction, &self.payer),
+            bidder_balance: pda::balance_pda(bid_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::SubmitBid {
+                computation_offset,
+                encrypted_amount,
+                pubkey: self.cipher.public_key(),
+                nonce,
+            },
+        )
+    }
+
+    /// Resolve an ended auction, revealing the winner and price
+    pub fn resolve_auction(&self, computation_offset: u64, auction: &Pubkey) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_RESOLVE_AUCTION);
+        let accounts = accounts::ResolveAuction {
+            payer: self.payer,
+            config: pda::config_pda(),
+            auction: *auction,
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(accounts, instruction::ResolveAuction { computation_offset })
+    }
+
+    /// Pay out the payer's escrowed bid on a resolved auction
+    ///
+    /// The winner is credited the lot and refunded any escrow above the
+    /// clearing price; every other bidder is refunded in full.
+    pub fn settle_bid(
+        &self,
+        computation_offset: u64,
+        auction: &Pubkey,
+        lot_mint: &Pubkey,
+        bid_mint: &Pubkey,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SETTLE_BID);
+        let accounts = accounts::SettleBid {
+            bidder: self.payer,
+            auction: *auction,
+            bid: pda::bid_pda(auction, &self.payer),
+            bid_balance: pda::balance_pda(bid_mint, &self.payer),
+            lot_balance: pda::balance_pda(lot_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(accounts, instruction::SettleBid { computation_offset })
+    }
+
+    /// Pay the payer the proceeds of their resolved auction `auction_id`, or
+    /// return the lot if no bid won
+    pub fn settle_auction(
+        &self,
+        computation_offset: u64,
+        auction_id: u64,
+        lot_mint: &Pubkey,
+        bid_mint: &Pubkey,
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_SETTLE_AUCTION);
+        let accounts = accounts::SettleAuction {
+            seller: self.payer,
+            auction: pda::auction_pda(&self.payer, auction_id),
+            bid_balance: pda::balance_pda(bid_mint, &self.payer),
+            lot_balance: pda::balance_pda(lot_mint, &self.payer),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(accounts, instruction::SettleAuction { computation_offset })
+    }
+
+    /// Disclose the payer's `mint` balance to `auditor`, encrypted to
+    /// `auditor_encryption_key`
+    ///
+    /// The auditor decrypts the resulting `BalanceDisclosedEvent` with a
+    /// [`ClientCipher`] built from their own X25519 secret.
+    pub fn disclose_balance(
+        &self,
+        computation_offset: u64,
+        disclosure_id: u64,
+        mint: &Pubkey,
+        auditor: &Pubkey,
+        auditor_encryption_key: [u8; 32],
+    ) -> Instruction {
+        let arcium = self.arcium_accounts(computation_offset, COMP_DEF_OFFSET_DISCLOSE_BALANCE);
+        let accounts = accounts::DiscloseBalance {
+            payer: self.payer,
+            config: pda::config_pda(),
+            balance_account: pda::balance_pda(mint, &self.payer),
+            disclosure: pda::disclosure_pda(&self.payer, disclosure_id),
+            computation_request: arcium.computation_request,
+            sign_pda_account: arcium.sign_pda_account,
+            mxe_account: arcium.mxe_account,
+            mempool_account: arcium.mempool_account,
+            executing_pool: arcium.executing_pool,
+            computation_account: arcium.computation_account,
+            comp_def_account: arcium.comp_def_account,
+            cluster_account: arcium.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            system_program: system_program::ID,
+            arcium_program: ARCIUM_PROGRAM_ID,
+        };
+
+        build(
+            accounts,
+            instruction::DiscloseBalance {
+                computation_offset,
+                disclosure_id,
+                auditor: *auditor,
+                auditor_encryption_key,
+            },
+        )
+    }
+
+    /// Register `viewing_key` on the payer's `mint` balance, or remove it
+    /// with `None`
+    ///
+    /// A watcher holding the matching X25519 secret decrypts the balance's
+    /// `TransferSentViewEvent`, `BatchTransferSentViewEvent` and
+    /// `TransferReceivedViewEvent`s with a [`ClientCipher`] built from it.
+    pub fn set_viewing_key(&self, mint: &Pubkey, viewing_key: Option<[u8; 32]>) -> Instruction {
+        let accounts = accounts::SetViewingKey {
+            payer: self.payer,
+            balance_account: pda::balance_pda(mint, &self.payer),
+        };
+
+        build(accounts, instruction::SetViewingKey { viewing_key })
+    }
+
+    /// Cancel a timed-out computation that was never called back
+    ///
+    /// Any payer may send it. `requester` and `locked_accounts` are the
+    /// request's `ComputationRequest::requester` and
+    /// `ComputationRequest::locked_accounts`, in stored order.
+    /// `refund` is `(mint, token_account)` and is required to reclaim a
+    /// deposit, whose tokens are returned to the requester's `token_account`.
+    pub fn reclaim_expired_computation(
+        &self,
+        computation_offset: u64,
+        requester: &Pubkey,
+        locked_accounts: &[Pubkey],
+        refund: Option<(&Pubkey, &Pubkey)>,
+    ) -> Instruction {
+        let accounts = accounts::ReclaimExpiredComputation {
+            payer: self.payer,
+            config: pda::config_pda(),
+            computation_request: pda::computation_request_pda(computation_offset),
+            requester: *requester,
+            mint: refund.map(|(mint, _)| *mint),
+            vault: refund.map(|(mint, _)| pda::vault_pda(mint)),
+            refund_token_account: refund.map(|(_, token_account)| *token_account),
+            token_program: refund.map(|_| anchor_spl::token::ID),
+        };
+
+        let mut ix = build(accounts, instruction::ReclaimExpiredComputation {});
+        ix.accounts.extend(
+            locked_accounts
+                .iter()
+                .map(|account| AccountMeta::new(*account, false)),
+        );
+        ix
+    }
+
+    fn arcium_accounts(&self, computation_offset: u64, comp_def_offset: u32) -> ArciumAccounts {
+        ArciumAccounts {
+            computation_request: pda::computation_request_pda(computation_offset),
+            sign_pda_account: pda::sign_pda(),
+            mxe_account: pda::mxe_pda(),
+            mempool_account: pda::mempool_pda(self.cluster_offset),
+            executing_pool: pda::execpool_pda(self.cluster_offset),
+            computation_account: pda::comp_pda(self.cluster_offset, computation_offset),
+            comp_def_account: pda::comp_def_pda(comp_def_offset),
+            cluster_account: pda::cluster_pda(self.cluster_offset),
+        }
+    }
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
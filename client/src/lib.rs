@@ -0,0 +1,29 @@
+//! Rust client for the `sip_arcium_transfer` program
+//!
+//! Derives every account the program and the Arcium network expect, encrypts
+//! circuit inputs for the MXE, and builds ready-to-sign instructions, so
+//! callers never hand-assemble the `[u8; 32]` ciphertext arguments.
+//!
+//! ```ignore
+//! let cipher = ClientCipher::new(secret, mxe_x25519_pubkey)?;
+//! let builder = InstructionBuilder::new(payer, cluster_offset, cipher);
+//! let ix = builder.private_transfer(
+//!     computation_offset,
+//!     &mint,
+//!     &recipient,
+//!     amount,
+//!     min_balance,
+//!     sequence,
+//!     charges_fee,
+//! );
+//! ```
+
+pub mod cipher;
+pub mod events;
+pub mod instruction;
+pub mod pda;
+
+pub use cipher::{public_key, random_nonce, random_secret, CipherError, ClientCipher};
+pub use events::{parse_logs, DecryptEvent, SipEvent};
+pub use instruction::InstructionBuilder;
+pub use sip_arcium_transfer::ID as PROGRAM_ID;
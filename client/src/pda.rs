@@ -0,0 +1,144 @@
+//! Program Derived Addresses used by `sip_arcium_transfer`
+//!
+//! Arcium accounts are keyed by the cluster the MXE is deployed to, so the
+//! caller supplies `cluster_offset` (the value stored in `MXEAccount::cluster`).
+
+use anchor_lang::{prelude::Pubkey, solana_program::bpf_loader_upgradeable};
+use arcium_anchor::SIGN_PDA_SEED;
+use arcium_client::pda;
+use sip_arcium_transfer::{
+    AUCTION_SEED, BALANCE_SEED, BID_SEED, COMPUTATION_REQUEST_SEED, CONFIG_SEED, DISCLOSURE_SEED,
+    FEE_RECORD_SEED, ID, ORDER_SEED, POOL_SEED, PRICE_FEED_SEED, TREASURY_SEED, VAULT_SEED,
+};
+
+/// Global `ProgramConfig`
+pub fn config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &ID).0
+}
+
+/// Upgradeable-loader `ProgramData` account holding the upgrade authority
+pub fn program_data_pda() -> Pubkey {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// Program-owned signer that queues computations with the Arcium program
+pub fn sign_pda() -> Pubkey {
+    Pubkey::find_program_address(&[SIGN_PDA_SEED], &ID).0
+}
+
+/// MXE account for this program
+pub fn mxe_pda() -> Pubkey {
+    pda::mxe_acc(&ID)
+}
+
+/// Cluster mempool
+pub fn mempool_pda(cluster_offset: u32) -> Pubkey {
+    pda::mempool_acc(cluster_offset)
+}
+
+/// Cluster executing pool
+pub fn execpool_pda(cluster_offset: u32) -> Pubkey {
+    pda::execpool_acc(cluster_offset)
+}
+
+/// Computation account for a single queued computation
+pub fn comp_pda(cluster_offset: u32, computation_offset: u64) -> Pubkey {
+    pda::computation_acc(cluster_offset, computation_offset)
+}
+
+/// Computation definition for one of the program's circuits
+///
+/// `comp_def_offset` is one of the `COMP_DEF_OFFSET_*` constants.
+pub fn comp_def_pda(comp_def_offset: u32) -> Pubkey {
+    pda::computation_definition_acc(&ID, comp_def_offset)
+}
+
+/// Cluster account
+pub fn cluster_pda(cluster_offset: u32) -> Pubkey {
+    pda::cluster_acc(cluster_offset)
+}
+
+/// `EncryptedBalanceAccount` of `owner` for `mint`
+pub fn balance_pda(mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[BALANCE_SEED, mint.as_ref(), owner.as_ref()], &ID).0
+}
+
+/// Per-mint token vault
+pub fn vault_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &ID).0
+}
+
+/// Per-mint fee `Treasury`
+pub fn treasury_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED, mint.as_ref()], &ID).0
+}
+
+/// `FeeRecord` written by the transfer queued at `computation_offset`
+pub fn fee_record_pda(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(&[FEE_RECORD_SEED, &computation_offset.to_le_bytes()], &ID).0
+}
+
+/// `PriceFeed` for swapping `input_mint` into `output_mint`
+pub fn price_feed_pda(input_mint: &Pubkey, output_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PRICE_FEED_SEED, input_mint.as_ref(), output_mint.as_ref()],
+        &ID,
+    )
+    .0
+}
+
+/// Constant-product `Pool` for a mint pair, given in either order
+pub fn pool_pda(mint_x: &Pubkey, mint_y: &Pubkey) -> Pubkey {
+    let (mint_a, mint_b) = pool_mints(mint_x, mint_y);
+    Pubkey::find_program_address(&[POOL_SEED, mint_a.as_ref(), mint_b.as_ref()], &ID).0
+}
+
+/// Order a mint pair as the pool stores it, `(mint_a, mint_b)`
+pub fn pool_mints<'a>(mint_x: &'a Pubkey, mint_y: &'a Pubkey) -> (&'a Pubkey, &'a Pubkey) {
+    if mint_x < mint_y {
+        (mint_x, mint_y)
+    } else {
+        (mint_y, mint_x)
+    }
+}
+
+/// `LimitOrder` placed by `owner` with `order_id`
+pub fn order_pda(owner: &Pubkey, order_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ORDER_SEED, owner.as_ref(), &order_id.to_le_bytes()], &ID).0
+}
+
+/// `SealedAuction` opened by `seller` with `auction_id`
+pub fn auction_pda(seller: &Pubkey, auction_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[AUCTION_SEED, seller.as_ref(), &auction_id.to_le_bytes()],
+        &ID,
+    )
+    .0
+}
+
+/// `SealedBid` placed by `bidder` on `auction`
+pub fn bid_pda(auction: &Pubkey, bidder: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[BID_SEED, auction.as_ref(), bidder.as_ref()], &ID).0
+}
+
+/// `BalanceDisclosure` made by `owner` with `disclosure_id`
+pub fn disclosure_pda(owner: &Pubkey, disclosure_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            DISCLOSURE_SEED,
+            owner.as_ref(),
+            &disclosure_id.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// `ComputationRequest` tracking the computation queued at `computation_offset`
+pub fn computation_request_pda(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[COMPUTATION_REQUEST_SEED, &computation_offset.to_le_bytes()],
+        &ID,
+    )
+    .0
+}
@@ -0,0 +1,38 @@
+//! Client cipher round trips and key agreement with the MXE side
+
+use sip_arcium_client::{public_key, CipherError, ClientCipher};
+
+const CLIENT_SECRET: [u8; 32] = [7u8; 32];
+const MXE_SECRET: [u8; 32] = [42u8; 32];
+
+#[test]
+fn encrypt_decrypt_round_trip() {
+    let cipher = ClientCipher::new(CLIENT_SECRET, public_key(MXE_SECRET)).unwrap();
+    let values = [0, 1, u64::MAX as u128, u128::MAX];
+
+    let ciphertexts = cipher.encrypt(values, 1234);
+    assert_eq!(cipher.decrypt(&ciphertexts, 1234).unwrap(), values);
+}
+
+#[test]
+fn both_sides_derive_the_same_key() {
+    let client = ClientCipher::new(CLIENT_SECRET, public_key(MXE_SECRET)).unwrap();
+    let mxe = ClientCipher::new(MXE_SECRET, public_key(CLIENT_SECRET)).unwrap();
+
+    let ciphertexts = client.encrypt([500, 100], 99);
+    assert_eq!(mxe.decrypt(&ciphertexts, 99).unwrap(), [500, 100]);
+}
+
+#[test]
+fn nonce_changes_ciphertext() {
+    let cipher = ClientCipher::new(CLIENT_SECRET, public_key(MXE_SECRET)).unwrap();
+    assert_ne!(cipher.encrypt([500], 1), cipher.encrypt([500], 2));
+}
+
+#[test]
+fn rejects_invalid_public_key() {
+    assert_eq!(
+        ClientCipher::new(CLIENT_SECRET, [0xff; 32]).err(),
+        Some(CipherError::InvalidPublicKey)
+    );
+}
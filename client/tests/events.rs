@@ -0,0 +1,402 @@
+//! Event log parsing and decryption
+
+use anchor_lang::Event;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sip_arcium_client::{
+    events::{
+        AuctionFill, BatchTransferSentView, CpmmSwapResult, IncomingClaim,
+        OracleSwapValidationResult, OrderFill, PrivateTransferResult, SwapValidationResult,
+        TransferSentView, TransferStatus,
+    },
+    parse_logs, public_key, ClientCipher, DecryptEvent, SipEvent, PROGRAM_ID,
+};
+use sip_arcium_transfer::{
+    AuctionClearedEvent, AuctionFillEvent, BalanceCheckEvent, BalanceDisclosedEvent,
+    BatchTransferEvent, BatchTransferSentViewEvent, CpmmSwapEvent, DepositEvent,
+    IncomingClaimedEvent, LiquidityAddedEvent, LiquidityRemovedEvent, OracleSwapValidationEvent,
+    OrderFilledEvent, OrderPlacedEvent, OrdersMatchedEvent, PrivateTransferEvent,
+    SealedBidSubmittedEvent, SwapValidationEvent, TransferSentViewEvent, AUCTION_SIZE, BATCH_SIZE,
+};
+
+const CLIENT_SECRET: [u8; 32] = [7u8; 32];
+const MXE_SECRET: [u8; 32] = [42u8; 32];
+
+fn ciphers() -> (ClientCipher, ClientCipher) {
+    let client = ClientCipher::new(CLIENT_SECRET, public_key(MXE_SECRET)).unwrap();
+    let mxe = ClientCipher::new(MXE_SECRET, public_key(CLIENT_SECRET)).unwrap();
+    (client, mxe)
+}
+
+fn data_log(event: &impl Event) -> String {
+    format!("Program data: {}", STANDARD.encode(event.data()))
+}
+
+#[test]
+fn parses_only_program_events() {
+    let deposit = DepositEvent {
+        owner: PROGRAM_ID,
+        mint: PROGRAM_ID,
+        amount: 500,
+    };
+    let other = "11111111111111111111111111111111";
+    let logs = [
+        format!("Program {other} invoke [1]"),
+        data_log(&deposit),
+        format!("Program {PROGRAM_ID} invoke [2]"),
+        "Program log: Instruction: Deposit".to_string(),
+        data_log(&deposit),
+        format!("Program {PROGRAM_ID} success"),
+        data_log(&deposit),
+        format!("Program {other} success"),
+    ];
+
+    let events = parse_logs(logs.iter().map(String::as_str));
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], SipEvent::Deposit(e) if e.amount == 500));
+}
+
+#[test]
+fn decrypts_private_transfer_event() {
+    let (client, mxe) = ciphers();
+    let nonce = 77u128;
+    let [is_valid, new_sender_balance, error_code, fee] = mxe.encrypt([1, 400, 0, 3], nonce);
+    let event = PrivateTransferEvent {
+        is_valid,
+        new_sender_balance,
+        error_code,
+        fee,
+        nonce: nonce.to_le_bytes(),
+    };
+
+    let logs = [
+        format!("Program {PROGRAM_ID} invoke [1]"),
+        data_log(&event),
+        format!("Program {PROGRAM_ID} success"),
+    ];
+    let events = parse_logs(logs.iter().map(String::as_str));
+    let SipEvent::PrivateTransfer(event) = &events[0] else {
+        panic!("expected PrivateTransferEvent");
+    };
+
+    assert_eq!(
+        event.decrypt(&client).unwrap(),
+        PrivateTransferResult {
+            is_valid: true,
+            new_sender_balance: 400,
+            status: TransferStatus::Ok,
+            fee: 3,
+        }
+    );
+}
+
+#[test]
+fn decrypts_batch_transfer_event() {
+    let (client, mxe) = ciphers();
+    let nonce = 78u128;
+    let [is_valid, new_sender_balance, error_code, fee] = mxe.encrypt([0, 900, 1, 0], nonce);
+    let event = BatchTransferEvent {
+        recipients: [PROGRAM_ID; BATCH_SIZE],
+        is_valid,
+        new_sender_balance,
+        error_code,
+        fee,
+        nonce: nonce.to_le_bytes(),
+    };
+
+    let logs = [
+        format!("Program {PROGRAM_ID} invoke [1]"),
+        data_log(&event),
+        format!("Program {PROGRAM_ID} success"),
+    ];
+    let events = parse_logs(logs.iter().map(String::as_str));
+    let SipEvent::BatchTransfer(event) = &events[0] else {
+        panic!("expected BatchTransferEvent");
+    };
+
+    assert_eq!(
+        event.decrypt(&client).unwrap(),
+        PrivateTransferResult {
+            is_valid: false,
+            new_sender_balance: 900,
+            status: TransferStatus::InsufficientFunds,
+            fee: 0,
+        }
+    );
+}
+
+#[test]
+fn decrypts_balance_check_and_swap_events() {
+    let (client, mxe) = ciphers();
+
+    let [meets_minimum] = mxe.encrypt([1], 5);
+    let check = BalanceCheckEvent {
+        meets_minimum,
+        nonce: 5u128.to_le_bytes(),
+    };
+    assert!(check.decrypt(&client).unwrap());
+
+    let [is_valid, new_input_balance, slippage_ok, new_output_balance] =
+        mxe.encrypt([0, 1000, 0, 250], 6);
+    let swap = SwapValidationEvent {
+        is_valid,
+        new_input_balance,
+        slippage_ok,
+        new_output_balance,
+        nonce: 6u128.to_le_bytes(),
+    };
+    assert_eq!(
+        swap.decrypt(&client).unwrap(),
+        SwapValidationResult {
+            is_valid: false,
+            new_input_balance: 1000,
+            slippage_ok: false,
+            new_output_balance: 250,
+        }
+    );
+
+    let [is_valid, slippage_ok, expected_output] = mxe.encrypt([1, 1, 2_000], 7);
+    let oracle_swap = OracleSwapValidationEvent {
+        is_valid,
+        slippage_ok,
+        expected_output,
+        nonce: 7u128.to_le_bytes(),
+    };
+    assert_eq!(
+        oracle_swap.decrypt(&client).unwrap(),
+        OracleSwapValidationResult {
+            is_valid: true,
+            slippage_ok: true,
+            expected_output: 2_000,
+        }
+    );
+}
+
+#[test]
+fn decrypts_pool_events() {
+    let (client, mxe) = ciphers();
+
+    let [is_valid] = mxe.encrypt([1], 8);
+    let liquidity = LiquidityAddedEvent {
+        pool: PROGRAM_ID,
+        is_valid,
+        nonce: 8u128.to_le_bytes(),
+    };
+    assert!(liquidity.decrypt(&client).unwrap());
+
+    let [is_valid] = mxe.encrypt([0], 10);
+    let removed = LiquidityRemovedEvent {
+        pool: PROGRAM_ID,
+        is_valid,
+        nonce: 10u128.to_le_bytes(),
+    };
+    assert!(!removed.decrypt(&client).unwrap());
+
+    let [is_valid, amount_out, fee] = mxe.encrypt([1, 1_813, 3], 9);
+    let swap = CpmmSwapEvent {
+        pool: PROGRAM_ID,
+        is_valid,
+        amount_out,
+        fee,
+        nonce: 9u128.to_le_bytes(),
+    };
+    assert_eq!(
+        swap.decrypt(&client).unwrap(),
+        CpmmSwapResult {
+            is_valid: true,
+            amount_out: 1_813,
+            fee: 3,
+        }
+    );
+}
+
+#[test]
+fn decrypts_order_events() {
+    let (client, mxe) = ciphers();
+
+    let [is_valid] = mxe.encrypt([1], 9);
+    let placed = OrderPlacedEvent {
+        order: PROGRAM_ID,
+        owner: PROGRAM_ID,
+        base_mint: PROGRAM_ID,
+        quote_mint: PROGRAM_ID,
+        is_valid,
+        nonce: 9u128.to_le_bytes(),
+    };
+    assert!(placed.decrypt(&client).unwrap());
+
+    let [matched] = mxe.encrypt([0], 11);
+    let outcome = OrdersMatchedEvent {
+        order_a: PROGRAM_ID,
+        order_b: PROGRAM_ID,
+        matched,
+        nonce: 11u128.to_le_bytes(),
+    };
+    assert!(!outcome.decrypt(&client).unwrap());
+
+    let [filled, remaining, proceeds] = mxe.encrypt([40, 60, 120], 10);
+    let fill = OrderFilledEvent {
+        order: PROGRAM_ID,
+        owner: PROGRAM_ID,
+        filled,
+        remaining,
+        proceeds,
+        nonce: 10u128.to_le_bytes(),
+    };
+    assert_eq!(
+        fill.decrypt(&client).unwrap(),
+        OrderFill {
+            filled: 40,
+            remaining: 60,
+            proceeds: 120,
+        }
+    );
+}
+
+#[test]
+fn decrypts_batch_auction_events() {
+    let (client, mxe) = ciphers();
+
+    let [cleared] = mxe.encrypt([1], 12);
+    let outcome = AuctionClearedEvent {
+        orders: [PROGRAM_ID; AUCTION_SIZE],
+        cleared,
+        nonce: 12u128.to_le_bytes(),
+    };
+    assert!(outcome.decrypt(&client).unwrap());
+
+    let [filled, clearing_price, remaining, proceeds] =
+        mxe.encrypt([250, 3_000_000_000, 0, 750], 11);
+    let fill = AuctionFillEvent {
+        order: PROGRAM_ID,
+        owner: PROGRAM_ID,
+        filled,
+        clearing_price,
+        remaining,
+        proceeds,
+        nonce: 11u128.to_le_bytes(),
+    };
+    assert_eq!(
+        fill.decrypt(&client).unwrap(),
+        AuctionFill {
+            filled: 250,
+            clearing_price: 3_000_000_000,
+            remaining: 0,
+            proceeds: 750,
+        }
+    );
+}
+
+#[test]
+fn decrypts_sealed_bid_submitted_event() {
+    let (client, mxe) = ciphers();
+
+    let [is_valid] = mxe.encrypt([0], 13);
+    let bid = SealedBidSubmittedEvent {
+        auction: PROGRAM_ID,
+        bidder: PROGRAM_ID,
+        bid_index: 2,
+        is_valid,
+        nonce: 13u128.to_le_bytes(),
+    };
+    assert!(!bid.decrypt(&client).unwrap());
+}
+
+#[test]
+fn decrypts_balance_disclosed_event() {
+    let (auditor, mxe) = ciphers();
+
+    let [encrypted_balance] = mxe.encrypt([12_345], 12);
+    let disclosure = BalanceDisclosedEvent {
+        disclosure: PROGRAM_ID,
+        owner: PROGRAM_ID,
+        balance_account: PROGRAM_ID,
+        auditor: PROGRAM_ID,
+        sequence: 3,
+        encrypted_balance,
+        nonce: 12u128.to_le_bytes(),
+    };
+    assert_eq!(disclosure.decrypt(&auditor).unwrap(), 12_345);
+}
+
+#[test]
+fn decrypts_transfer_sent_view_event() {
+    let (watcher, mxe) = ciphers();
+
+    let [amount, fee, new_sender_balance] = mxe.encrypt([250, 2, 748], 31);
+    let view = TransferSentViewEvent {
+        balance_account: PROGRAM_ID,
+        recipient: PROGRAM_ID,
+        sequence: 4,
+        amount,
+        fee,
+        new_sender_balance,
+        nonce: 31u128.to_le_bytes(),
+    };
+    assert_eq!(
+        view.decrypt(&watcher).unwrap(),
+        TransferSentView {
+            amount: 250,
+            fee: 2,
+            new_sender_balance: 748,
+        }
+    );
+}
+
+#[test]
+fn decrypts_batch_transfer_sent_view_event() {
+    let (watcher, mxe) = ciphers();
+
+    let [a0, a1, a2, a3, fee, new_sender_balance] = mxe.encrypt([10, 20, 0, 30, 1, 939], 32);
+    let view = BatchTransferSentViewEvent {
+        balance_account: PROGRAM_ID,
+        recipients: [PROGRAM_ID; BATCH_SIZE],
+        sequence: 5,
+        amounts: [a0, a1, a2, a3],
+        fee,
+        new_sender_balance,
+        nonce: 32u128.to_le_bytes(),
+    };
+    assert_eq!(
+        view.decrypt(&watcher).unwrap(),
+        BatchTransferSentView {
+            amounts: [10, 20, 0, 30],
+            fee: 1,
+            new_sender_balance: 939,
+        }
+    );
+}
+
+#[test]
+fn decrypts_incoming_claimed_event() {
+    let (owner, mxe) = ciphers();
+
+    let [claimed, new_balance] = mxe.encrypt([600, 1_348], 33);
+    let claim = IncomingClaimedEvent {
+        owner: PROGRAM_ID,
+        mint: PROGRAM_ID,
+        sequence: 6,
+        claimed,
+        new_balance,
+        nonce: 33u128.to_le_bytes(),
+    };
+    assert_eq!(
+        claim.decrypt(&owner).unwrap(),
+        IncomingClaim {
+            claimed: 600,
+            new_balance: 1_348,
+        }
+    );
+}
+
+#[test]
+fn wrong_key_does_not_decrypt() {
+    let (_, mxe) = ciphers();
+    let stranger = ClientCipher::new([3u8; 32], public_key(MXE_SECRET)).unwrap();
+
+    let [meets_minimum] = mxe.encrypt([1], 5);
+    let check = BalanceCheckEvent {
+        meets_minimum,
+        nonce: 5u128.to_le_bytes(),
+    };
+    assert!(check.decrypt(&stranger).is_err());
+}
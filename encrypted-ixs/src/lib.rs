@@ -3,7 +3,7 @@ use arcis::*;
 /// SIP Private Transfer Circuit
 ///
 /// Enables confidential token transfers where:
-/// - Sender's balance is encrypted
+/// - Sender's balance is MXE-encrypted and stored on-chain
 /// - Transfer amount is encrypted
 /// - Recipient address is encrypted
 /// - Balance validation happens in MPC (no one sees actual values)
@@ -15,61 +15,483 @@ use arcis::*;
 mod circuits {
     use arcis::*;
 
+    /// Create a new MXE-owned balance, starting at zero
+    ///
+    /// Returns the spendable balance and the incoming balance that transfers
+    /// credit, both stored in the user's `EncryptedBalanceAccount`. They are
+    /// the only balances the other circuits trust.
+    #[instruction]
+    pub fn create_balance() -> (Enc<Mxe, u64>, Enc<Mxe, u64>) {
+        (Mxe::get().from_arcis(0u64), Mxe::get().from_arcis(0u64))
+    }
+
+    /// Credit a public deposit to an MXE-owned balance
+    ///
+    /// The tokens have already moved into the program vault, so the amount is
+    /// plaintext. The vault holds at least the sum of all balances for its
+    /// mint, which bounds every balance below `u64::MAX`.
+    #[instruction]
+    pub fn deposit(balance_ctxt: Enc<Mxe, u64>, amount: u64) -> Enc<Mxe, u64> {
+        let balance = balance_ctxt.to_arcis();
+        balance_ctxt.owner.from_arcis(balance + amount)
+    }
+
+    /// Debit a public withdrawal from an MXE-owned balance
+    ///
+    /// Returns the updated balance and the amount the program may release from
+    /// the vault, which is revealed: either the full `amount` or zero if the
+    /// balance is insufficient.
+    #[instruction]
+    pub fn withdraw(balance_ctxt: Enc<Mxe, u64>, amount: u64) -> (Enc<Mxe, u64>, u64) {
+        let balance = balance_ctxt.to_arcis();
+
+        let has_funds = balance >= amount;
+        let (new_balance, released) = if has_funds {
+            (balance - amount, amount)
+        } else {
+            (balance, 0)
+        };
+
+        (balance_ctxt.owner.from_arcis(new_balance), released.reveal())
+    }
+
+    /// Result of a `claim_incoming`, for the owner and their viewing key
+    pub struct IncomingClaim {
+        /// Amount moved from the incoming to the spendable balance
+        claimed: u64,
+        /// New spendable balance
+        new_balance: u64,
+    }
+
+    /// Move everything credited to an incoming balance into the spendable one
+    ///
+    /// Leaves both unchanged if the sum would exceed `u64::MAX`, which the
+    /// vault backing every balance of the mint rules out in practice.
+    #[instruction]
+    pub fn claim_incoming(
+        balance_ctxt: Enc<Mxe, u64>,
+        incoming_ctxt: Enc<Mxe, u64>,
+        owner_key: ArcisX25519Pubkey,
+        view_key: ArcisX25519Pubkey,
+    ) -> (
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Shared, IncomingClaim>,
+        Enc<Shared, IncomingClaim>,
+    ) {
+        let balance = balance_ctxt.to_arcis();
+        let incoming = incoming_ctxt.to_arcis();
+
+        let overflows = incoming > u64::MAX - balance;
+        let (new_balance, new_incoming, claimed) = if overflows {
+            (balance, incoming, 0)
+        } else {
+            (balance + incoming, 0, incoming)
+        };
+        let claim = IncomingClaim {
+            claimed,
+            new_balance,
+        };
+
+        (
+            balance_ctxt.owner.from_arcis(new_balance),
+            incoming_ctxt.owner.from_arcis(new_incoming),
+            Shared::new(owner_key).from_arcis(claim),
+            Shared::new(view_key).from_arcis(claim),
+        )
+    }
+
     /// Input for private transfer validation
     /// All values are encrypted - MXE nodes compute without seeing plaintext
     pub struct PrivateTransferInput {
-        /// Sender's current balance (in smallest units, e.g., lamports)
-        sender_balance: u64,
         /// Amount to transfer
         amount: u64,
         /// Minimum balance to maintain (for rent exemption)
         min_balance: u64,
+        /// Recipient wallet address, low 16 bytes (little-endian)
+        recipient_lo: u128,
+        /// Recipient wallet address, high 16 bytes (little-endian)
+        recipient_hi: u128,
+        /// `EncryptedBalanceAccount::sequence` of the sender balance the
+        /// input was built against
+        sequence: u64,
     }
 
+    /// `PrivateTransferOutput::error_code`: transfer applied
+    pub const TRANSFER_OK: u8 = 0;
+    /// `PrivateTransferOutput::error_code`: balance below
+    /// `amount + fee + min_balance`
+    pub const TRANSFER_INSUFFICIENT_FUNDS: u8 = 1;
+    /// `PrivateTransferOutput::error_code`: `amount + fee + min_balance` or the
+    /// recipient's incoming credit exceeds `u64::MAX`
+    pub const TRANSFER_OVERFLOW: u8 = 2;
+    /// `PrivateTransferOutput::error_code`: encrypted recipient does not own
+    /// the credited balance account
+    pub const TRANSFER_RECIPIENT_MISMATCH: u8 = 3;
+    /// `PrivateTransferOutput::error_code`: encrypted sequence does not match
+    /// the sender balance, the input was built against an outdated balance
+    pub const TRANSFER_STALE_SEQUENCE: u8 = 4;
+
+    /// Fee rates are in basis points of the transferred amount
+    pub const BPS_DENOMINATOR: u128 = 10_000;
+
     /// Output of private transfer
     pub struct PrivateTransferOutput {
-        /// Whether the transfer is valid (sufficient balance)
+        /// Whether the transfer was applied (`error_code == TRANSFER_OK`)
         is_valid: bool,
         /// New sender balance after transfer
         new_sender_balance: u64,
+        /// Why the transfer was rejected, one of the `TRANSFER_*` codes
+        error_code: u8,
+        /// Protocol fee debited on top of `amount` (zero if rejected)
+        fee: u64,
+    }
+
+    /// Receipt for the recipient of a private transfer
+    pub struct TransferReceipt {
+        /// Amount credited (zero if the transfer was rejected)
+        amount: u64,
+        /// Recipient's unclaimed incoming balance after the transfer
+        new_incoming: u64,
+    }
+
+    /// Debit of a private transfer, for the sender's viewing key
+    pub struct TransferDebit {
+        /// Amount sent (zero if the transfer was rejected)
+        amount: u64,
+        /// Protocol fee debited on top of `amount` (zero if rejected)
+        fee: u64,
+        /// New sender balance after transfer
+        new_sender_balance: u64,
     }
 
     /// Validate and compute a private transfer
     ///
+    /// Two-sided ledger update: the sender's spendable balance and the
+    /// recipient's incoming balance are both read and both returned. The
+    /// recipient's spendable balance is never touched, so incoming transfers
+    /// cannot lock it or invalidate a transfer the recipient has prepared;
+    /// `claim_incoming` moves the credit over. The sender names the recipient
+    /// inside the encrypted input, and the MXE only credits the incoming
+    /// balance passed in if its owner matches that encrypted address.
+    ///
+    /// The protocol fee, `amount * fee_bps / 10_000` rounded down, is debited
+    /// from the sender on top of `amount` and returned on its own, encrypted
+    /// for the MXE, rather than credited to a shared treasury balance. The
+    /// treasury collects it later with `sweep_fee`.
+    ///
+    /// A single `is_valid` selects between applying every side or none, so
+    /// the total across the sender and recipient balances and the fee is
+    /// always conserved.
+    ///
+    /// The encrypted input carries the sender balance sequence it was built
+    /// against. Every balance write bumps the sequence, so replaying the same
+    /// input, or applying it to a balance that has changed since, is rejected.
+    ///
     /// MPC guarantees:
     /// - No single node sees the actual balance or amount
     /// - Computation is verifiable via threshold signatures
     /// - Result is encrypted with requester's key
+    /// - Receipt is encrypted with the recipient's registered key
+    /// - Both sides are also encrypted to each balance's viewing key
+    /// - Updated balances are re-encrypted for the MXE and written back on-chain
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     #[instruction]
     pub fn private_transfer(
         input_ctxt: Enc<Shared, PrivateTransferInput>,
-    ) -> Enc<Shared, PrivateTransferOutput> {
+        sender_balance_ctxt: Enc<Mxe, u64>,
+        sender_sequence: u64,
+        sender_view_key: ArcisX25519Pubkey,
+        recipient_incoming_ctxt: Enc<Mxe, u64>,
+        recipient_owner_lo: u128,
+        recipient_owner_hi: u128,
+        recipient_key: ArcisX25519Pubkey,
+        recipient_view_key: ArcisX25519Pubkey,
+        fee_bps: u16,
+    ) -> (
+        Enc<Shared, PrivateTransferOutput>,
+        Enc<Shared, TransferReceipt>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Shared, TransferDebit>,
+        Enc<Shared, TransferReceipt>,
+    ) {
         let input = input_ctxt.to_arcis();
+        let sender_balance = sender_balance_ctxt.to_arcis();
+        let recipient_incoming = recipient_incoming_ctxt.to_arcis();
+
+        // Widen before multiplying so large amounts cannot wrap
+        let fee = ((input.amount as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
 
         // Check if sender has sufficient balance (encrypted comparison)
-        // Both branches execute - MPC selects result without leaking which
-        let available = input.sender_balance - input.min_balance;
-        let is_valid = available >= input.amount;
+        // Both branches execute - MPC selects result without leaking which.
+        // Compare against `amount + fee + min_balance` rather than subtracting
+        // from the balance first, which would wrap when min_balance > balance.
+        let debit_overflows = fee > u64::MAX - input.amount;
+        let debit = input.amount + fee;
+        let required_overflows = debit_overflows || input.min_balance > u64::MAX - debit;
+        let required = debit + input.min_balance;
+        let has_funds = !required_overflows && sender_balance >= required;
+
+        // The encrypted recipient must own the balance account being credited
+        let recipient_matches =
+            input.recipient_lo == recipient_owner_lo && input.recipient_hi == recipient_owner_hi;
+
+        // The credit must not wrap the recipient's incoming balance
+        let credit_overflows = input.amount > u64::MAX - recipient_incoming;
+
+        // The input must have been built against the current sender balance
+        let sequence_matches = input.sequence == sender_sequence;
+
+        let error_code = if !sequence_matches {
+            TRANSFER_STALE_SEQUENCE
+        } else if required_overflows || credit_overflows {
+            TRANSFER_OVERFLOW
+        } else if !has_funds {
+            TRANSFER_INSUFFICIENT_FUNDS
+        } else if !recipient_matches {
+            TRANSFER_RECIPIENT_MISMATCH
+        } else {
+            TRANSFER_OK
+        };
+        let is_valid = error_code == TRANSFER_OK;
 
-        // Compute new balance (only meaningful if valid)
-        let new_balance = if is_valid {
-            input.sender_balance - input.amount
+        // Debit and credit together, or leave every balance unchanged
+        let (new_sender_balance, new_incoming, credited, charged) = if is_valid {
+            (
+                sender_balance - debit,
+                recipient_incoming + input.amount,
+                input.amount,
+                fee,
+            )
         } else {
-            input.sender_balance // No change if invalid
+            (sender_balance, recipient_incoming, 0, 0)
         };
 
         let output = PrivateTransferOutput {
             is_valid,
-            new_sender_balance: new_balance,
+            new_sender_balance,
+            error_code,
+            fee: charged,
+        };
+        let receipt = TransferReceipt {
+            amount: credited,
+            new_incoming,
+        };
+        let debit_view = TransferDebit {
+            amount: credited,
+            fee: charged,
+            new_sender_balance,
+        };
+        let credit_view = TransferReceipt {
+            amount: credited,
+            new_incoming,
         };
 
-        input_ctxt.owner.from_arcis(output)
+        (
+            input_ctxt.owner.from_arcis(output),
+            Shared::new(recipient_key).from_arcis(receipt),
+            sender_balance_ctxt.owner.from_arcis(new_sender_balance),
+            recipient_incoming_ctxt.owner.from_arcis(new_incoming),
+            Mxe::get().from_arcis(charged),
+            Shared::new(sender_view_key).from_arcis(debit_view),
+            Shared::new(recipient_view_key).from_arcis(credit_view),
+        )
+    }
+
+    /// Number of recipient slots in a `batch_transfer`
+    ///
+    /// Kept small so the callback, which writes one balance account per slot,
+    /// fits in a single transaction.
+    pub const BATCH_SIZE: usize = 4;
+
+    /// Input for a batch transfer, one slot per recipient balance
+    pub struct BatchTransferInput {
+        /// Amount credited to each slot, zero for unused slots
+        amounts: [u64; BATCH_SIZE],
+        /// Recipient wallet address per slot, low 16 bytes (little-endian)
+        recipients_lo: [u128; BATCH_SIZE],
+        /// Recipient wallet address per slot, high 16 bytes (little-endian)
+        recipients_hi: [u128; BATCH_SIZE],
+        /// Minimum balance to maintain after the whole batch
+        min_balance: u64,
+        /// `EncryptedBalanceAccount::sequence` of the sender balance the
+        /// input was built against
+        sequence: u64,
+    }
+
+    /// Debit of a batch transfer, for the sender's viewing key
+    pub struct BatchTransferDebit {
+        /// Amount sent to each slot (all zero if the batch was rejected)
+        amounts: [u64; BATCH_SIZE],
+        /// Protocol fee debited on top of the amounts (zero if rejected)
+        fee: u64,
+        /// New sender balance after the batch
+        new_sender_balance: u64,
+    }
+
+    /// Validate and compute a batch of private transfers from one sender
+    ///
+    /// Pays up to `BATCH_SIZE` recipients in one computation. Every slot is
+    /// checked like a `private_transfer`, the fee is the sum of the per-slot
+    /// fees, and the sender must cover the total plus fee plus `min_balance`.
+    /// Unused slots carry an encrypted zero amount and credit nothing, so they
+    /// skip the recipient check and can point at any other balance.
+    ///
+    /// The whole batch is applied or none of it: a single failing slot leaves
+    /// the sender and every recipient unchanged and charges no fee. Like a single
+    /// transfer, each slot credits the recipient's incoming balance. Recipients
+    /// get no receipt and see the credit once they claim it. The sender's
+    /// debit and each slot's credit are also encrypted to the balances'
+    /// viewing keys.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    #[instruction]
+    pub fn batch_transfer(
+        input_ctxt: Enc<Shared, BatchTransferInput>,
+        sender_balance_ctxt: Enc<Mxe, u64>,
+        sender_sequence: u64,
+        sender_view_key: ArcisX25519Pubkey,
+        recipient_incoming_ctxt: [Enc<Mxe, u64>; BATCH_SIZE],
+        recipient_owners_lo: [u128; BATCH_SIZE],
+        recipient_owners_hi: [u128; BATCH_SIZE],
+        recipient_view_keys: [ArcisX25519Pubkey; BATCH_SIZE],
+        fee_bps: u16,
+    ) -> (
+        Enc<Shared, PrivateTransferOutput>,
+        Enc<Mxe, u64>,
+        [Enc<Mxe, u64>; BATCH_SIZE],
+        Enc<Mxe, u64>,
+        Enc<Shared, BatchTransferDebit>,
+        [Enc<Shared, TransferReceipt>; BATCH_SIZE],
+    ) {
+        let input = input_ctxt.to_arcis();
+        let sender_balance = sender_balance_ctxt.to_arcis();
+
+        let mut recipient_incoming = [0u64; BATCH_SIZE];
+        let mut total = 0u64;
+        let mut fee = 0u64;
+        let mut sum_overflows = false;
+        let mut credit_overflows = false;
+        let mut recipients_match = true;
+        for i in 0..BATCH_SIZE {
+            let amount = input.amounts[i];
+            let incoming = recipient_incoming_ctxt[i].to_arcis();
+            recipient_incoming[i] = incoming;
+
+            // Same rounding as a single transfer of this amount
+            let slot_fee = ((amount as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
+            sum_overflows = sum_overflows || amount > u64::MAX - total || slot_fee > u64::MAX - fee;
+            total += amount;
+            fee += slot_fee;
+
+            credit_overflows = credit_overflows || amount > u64::MAX - incoming;
+
+            // Zero slots credit nothing, so they may name any balance
+            let slot_matches = input.recipients_lo[i] == recipient_owners_lo[i]
+                && input.recipients_hi[i] == recipient_owners_hi[i];
+            recipients_match = recipients_match && (amount == 0 || slot_matches);
+        }
+
+        let debit_overflows = sum_overflows || fee > u64::MAX - total;
+        let debit = total + fee;
+        let required_overflows = debit_overflows || input.min_balance > u64::MAX - debit;
+        let required = debit + input.min_balance;
+        let has_funds = !required_overflows && sender_balance >= required;
+
+        let sequence_matches = input.sequence == sender_sequence;
+
+        let error_code = if !sequence_matches {
+            TRANSFER_STALE_SEQUENCE
+        } else if required_overflows || credit_overflows {
+            TRANSFER_OVERFLOW
+        } else if !has_funds {
+            TRANSFER_INSUFFICIENT_FUNDS
+        } else if !recipients_match {
+            TRANSFER_RECIPIENT_MISMATCH
+        } else {
+            TRANSFER_OK
+        };
+        let is_valid = error_code == TRANSFER_OK;
+
+        let mut new_incoming = [0u64; BATCH_SIZE];
+        let mut credited = [0u64; BATCH_SIZE];
+        for i in 0..BATCH_SIZE {
+            new_incoming[i] = if is_valid {
+                recipient_incoming[i] + input.amounts[i]
+            } else {
+                recipient_incoming[i]
+            };
+            credited[i] = if is_valid { input.amounts[i] } else { 0 };
+        }
+        let (new_sender_balance, charged) = if is_valid {
+            (sender_balance - debit, fee)
+        } else {
+            (sender_balance, 0)
+        };
+
+        let output = PrivateTransferOutput {
+            is_valid,
+            new_sender_balance,
+            error_code,
+            fee: charged,
+        };
+        let debit_view = BatchTransferDebit {
+            amounts: credited,
+            fee: charged,
+            new_sender_balance,
+        };
+
+        (
+            input_ctxt.owner.from_arcis(output),
+            sender_balance_ctxt.owner.from_arcis(new_sender_balance),
+            [
+                Mxe::get().from_arcis(new_incoming[0]),
+                Mxe::get().from_arcis(new_incoming[1]),
+                Mxe::get().from_arcis(new_incoming[2]),
+                Mxe::get().from_arcis(new_incoming[3]),
+            ],
+            Mxe::get().from_arcis(charged),
+            Shared::new(sender_view_key).from_arcis(debit_view),
+            [
+                Shared::new(recipient_view_keys[0]).from_arcis(TransferReceipt {
+                    amount: credited[0],
+                    new_incoming: new_incoming[0],
+                }),
+                Shared::new(recipient_view_keys[1]).from_arcis(TransferReceipt {
+                    amount: credited[1],
+                    new_incoming: new_incoming[1],
+                }),
+                Shared::new(recipient_view_keys[2]).from_arcis(TransferReceipt {
+                    amount: credited[2],
+                    new_incoming: new_incoming[2],
+                }),
+                Shared::new(recipient_view_keys[3]).from_arcis(TransferReceipt {
+                    amount: credited[3],
+                    new_incoming: new_incoming[3],
+                }),
+            ],
+        )
+    }
+
+    /// Credit a fee collected by a transfer to the treasury authority's balance
+    ///
+    /// Returns the new balance and whether the fee was credited. The balance
+    /// is left unchanged if the sum would exceed `u64::MAX`, in which case the
+    /// fee record stays open to be swept later.
+    #[instruction]
+    pub fn sweep_fee(balance_ctxt: Enc<Mxe, u64>, fee_ctxt: Enc<Mxe, u64>) -> (Enc<Mxe, u64>, bool) {
+        let balance = balance_ctxt.to_arcis();
+        let fee = fee_ctxt.to_arcis();
+
+        let swept = fee <= u64::MAX - balance;
+        let new_balance = if swept { balance + fee } else { balance };
+
+        (balance_ctxt.owner.from_arcis(new_balance), swept.reveal())
     }
 
     /// Input for encrypted balance check (simpler use case)
     pub struct BalanceCheckInput {
-        /// Balance to check
-        balance: u64,
         /// Minimum required
         minimum: u64,
     }
@@ -77,16 +499,18 @@ mod circuits {
     /// Check if encrypted balance meets minimum threshold
     /// Useful for pre-validation before transfer
     #[instruction]
-    pub fn check_balance(input_ctxt: Enc<Shared, BalanceCheckInput>) -> Enc<Shared, bool> {
+    pub fn check_balance(
+        input_ctxt: Enc<Shared, BalanceCheckInput>,
+        balance_ctxt: Enc<Mxe, u64>,
+    ) -> Enc<Shared, bool> {
         let input = input_ctxt.to_arcis();
-        let result = input.balance >= input.minimum;
+        let balance = balance_ctxt.to_arcis();
+        let result = balance >= input.minimum;
         input_ctxt.owner.from_arcis(result)
     }
 
     /// Input for confidential swap validation
     pub struct ConfidentialSwapInput {
-        /// Input token balance
-        input_balance: u64,
         /// Input amount for swap
         input_amount: u64,
         /// Expected minimum output (slippage protection)
@@ -99,10 +523,12 @@ mod circuits {
     pub struct ConfidentialSwapOutput {
         /// Whether swap is valid
         is_valid: bool,
-        /// New input balance
+        /// Input token balance after the swap (unchanged if invalid)
         new_input_balance: u64,
         /// Whether slippage is acceptable
         slippage_ok: bool,
+        /// Output token balance after the swap (unchanged if invalid)
+        new_output_balance: u64,
     }
 
     /// Validate a confidential DEX swap
@@ -110,33 +536,1066 @@ mod circuits {
     /// Checks:
     /// 1. Sufficient input balance
     /// 2. Slippage within tolerance
+    /// 3. The output credit does not overflow
+    ///
+    /// The input balance is debited `input_amount` and the output balance is
+    /// credited `actual_output` only if every check passes; otherwise both
+    /// are returned unchanged.
     #[instruction]
     pub fn validate_swap(
         input_ctxt: Enc<Shared, ConfidentialSwapInput>,
+        input_balance_ctxt: Enc<Mxe, u64>,
+        output_balance_ctxt: Enc<Mxe, u64>,
     ) -> Enc<Shared, ConfidentialSwapOutput> {
         let input = input_ctxt.to_arcis();
+        let input_balance = input_balance_ctxt.to_arcis();
+        let output_balance = output_balance_ctxt.to_arcis();
 
         // Check sufficient balance
-        let has_balance = input.input_balance >= input.input_amount;
+        let has_balance = input_balance >= input.input_amount;
 
         // Check slippage
         let slippage_ok = input.actual_output >= input.min_output;
 
-        // Both must be true for valid swap
-        let is_valid = has_balance && slippage_ok;
+        // The credit must not wrap the output balance
+        let credit_overflows = input.actual_output > u64::MAX - output_balance;
 
-        let new_balance = if has_balance {
-            input.input_balance - input.input_amount
+        // All must hold for a valid swap
+        let is_valid = has_balance && slippage_ok && !credit_overflows;
+
+        // Debit and credit together, or leave both balances unchanged
+        let (new_input_balance, new_output_balance) = if is_valid {
+            (
+                input_balance - input.input_amount,
+                output_balance + input.actual_output,
+            )
         } else {
-            input.input_balance
+            (input_balance, output_balance)
         };
 
         let output = ConfidentialSwapOutput {
             is_valid,
-            new_input_balance: new_balance,
+            new_input_balance,
             slippage_ok,
+            new_output_balance,
         };
 
         input_ctxt.owner.from_arcis(output)
     }
+
+    /// Oracle prices are output base units per input base unit, scaled by 1e9
+    pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+    /// Input for oracle-priced swap validation
+    pub struct OracleSwapInput {
+        /// Input amount for swap
+        input_amount: u64,
+        /// Minimum acceptable output (slippage protection)
+        min_output: u64,
+    }
+
+    /// Output of oracle-priced swap validation
+    pub struct OracleSwapOutput {
+        /// Whether swap is valid
+        is_valid: bool,
+        /// Whether the oracle output meets `min_output`
+        slippage_ok: bool,
+        /// Output implied by the oracle price, saturating at `u64::MAX`
+        expected_output: u64,
+    }
+
+    /// Validate a confidential swap priced by an on-chain oracle
+    ///
+    /// Like `validate_swap`, but the output is computed inside MPC as
+    /// `input_amount * price / PRICE_SCALE`, rounded down, instead of being
+    /// supplied by the user. `price` is plaintext, read by the program from
+    /// the pair's price feed. The swap is valid only if the balance covers
+    /// `input_amount`, the oracle output meets the encrypted `min_output` and
+    /// the credit does not overflow.
+    ///
+    /// Quote only: no balance is debited or credited. There is no
+    /// counterparty holding the output tokens, so settling at the oracle
+    /// price would mint them.
+    #[instruction]
+    pub fn validate_oracle_swap(
+        input_ctxt: Enc<Shared, OracleSwapInput>,
+        input_balance_ctxt: Enc<Mxe, u64>,
+        output_balance_ctxt: Enc<Mxe, u64>,
+        price: u64,
+    ) -> Enc<Shared, OracleSwapOutput> {
+        let input = input_ctxt.to_arcis();
+        let input_balance = input_balance_ctxt.to_arcis();
+        let output_balance = output_balance_ctxt.to_arcis();
+
+        // Widen before multiplying; a u64 product always fits in u128
+        let expected_wide = (input.input_amount as u128) * (price as u128) / PRICE_SCALE;
+        let output_overflows = expected_wide > u64::MAX as u128;
+        let expected_output = if output_overflows {
+            u64::MAX
+        } else {
+            expected_wide as u64
+        };
+
+        let has_balance = input_balance >= input.input_amount;
+        let slippage_ok = expected_output >= input.min_output;
+        let credit_overflows = output_overflows || expected_output > u64::MAX - output_balance;
+        let is_valid = has_balance && slippage_ok && !credit_overflows;
+
+        let output = OracleSwapOutput {
+            is_valid,
+            slippage_ok,
+            expected_output,
+        };
+
+        input_ctxt.owner.from_arcis(output)
+    }
+
+    /// MXE-encrypted reserves of a constant-product pool
+    pub struct PoolReserves {
+        /// Reserve of the pool's `mint_a`
+        reserve_a: u64,
+        /// Reserve of the pool's `mint_b`
+        reserve_b: u64,
+    }
+
+    /// Create the reserves of a new pool, starting at zero
+    #[instruction]
+    pub fn init_pool() -> Enc<Mxe, PoolReserves> {
+        Mxe::get().from_arcis(PoolReserves {
+            reserve_a: 0,
+            reserve_b: 0,
+        })
+    }
+
+    /// Input for adding liquidity to a pool
+    pub struct LiquidityInput {
+        /// Amount of `mint_a` moved into the pool
+        amount_a: u64,
+        /// Amount of `mint_b` moved into the pool
+        amount_b: u64,
+    }
+
+    /// Move liquidity from a provider's encrypted balances into pool reserves
+    ///
+    /// Applied only if the provider covers both amounts and neither reserve
+    /// overflows; otherwise every balance is returned unchanged. Returns
+    /// whether it was applied, encrypted for the provider.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn add_liquidity(
+        input_ctxt: Enc<Shared, LiquidityInput>,
+        provider_a_ctxt: Enc<Mxe, u64>,
+        provider_b_ctxt: Enc<Mxe, u64>,
+        reserves_ctxt: Enc<Mxe, PoolReserves>,
+    ) -> (
+        Enc<Shared, bool>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, PoolReserves>,
+    ) {
+        let input = input_ctxt.to_arcis();
+        let provider_a = provider_a_ctxt.to_arcis();
+        let provider_b = provider_b_ctxt.to_arcis();
+        let reserves = reserves_ctxt.to_arcis();
+
+        let has_funds = provider_a >= input.amount_a && provider_b >= input.amount_b;
+        let reserves_overflow = input.amount_a > u64::MAX - reserves.reserve_a
+            || input.amount_b > u64::MAX - reserves.reserve_b;
+        let is_valid = has_funds && !reserves_overflow;
+
+        let (new_provider_a, new_provider_b, new_reserves) = if is_valid {
+            (
+                provider_a - input.amount_a,
+                provider_b - input.amount_b,
+                PoolReserves {
+                    reserve_a: reserves.reserve_a + input.amount_a,
+                    reserve_b: reserves.reserve_b + input.amount_b,
+                },
+            )
+        } else {
+            (provider_a, provider_b, reserves)
+        };
+
+        (
+            input_ctxt.owner.from_arcis(is_valid),
+            provider_a_ctxt.owner.from_arcis(new_provider_a),
+            provider_b_ctxt.owner.from_arcis(new_provider_b),
+            reserves_ctxt.owner.from_arcis(new_reserves),
+        )
+    }
+
+    /// Move liquidity from pool reserves back into a provider's encrypted balances
+    ///
+    /// The reverse of `add_liquidity`: applied only if the reserves cover
+    /// both amounts and neither balance overflows; otherwise every balance is
+    /// returned unchanged. Returns whether it was applied, encrypted for the
+    /// provider.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn remove_liquidity(
+        input_ctxt: Enc<Shared, LiquidityInput>,
+        provider_a_ctxt: Enc<Mxe, u64>,
+        provider_b_ctxt: Enc<Mxe, u64>,
+        reserves_ctxt: Enc<Mxe, PoolReserves>,
+    ) -> (
+        Enc<Shared, bool>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, PoolReserves>,
+    ) {
+        let input = input_ctxt.to_arcis();
+        let provider_a = provider_a_ctxt.to_arcis();
+        let provider_b = provider_b_ctxt.to_arcis();
+        let reserves = reserves_ctxt.to_arcis();
+
+        let has_reserves =
+            reserves.reserve_a >= input.amount_a && reserves.reserve_b >= input.amount_b;
+        let provider_overflows =
+            input.amount_a > u64::MAX - provider_a || input.amount_b > u64::MAX - provider_b;
+        let is_valid = has_reserves && !provider_overflows;
+
+        let (new_provider_a, new_provider_b, new_reserves) = if is_valid {
+            (
+                provider_a + input.amount_a,
+                provider_b + input.amount_b,
+                PoolReserves {
+                    reserve_a: reserves.reserve_a - input.amount_a,
+                    reserve_b: reserves.reserve_b - input.amount_b,
+                },
+            )
+        } else {
+            (provider_a, provider_b, reserves)
+        };
+
+        (
+            input_ctxt.owner.from_arcis(is_valid),
+            provider_a_ctxt.owner.from_arcis(new_provider_a),
+            provider_b_ctxt.owner.from_arcis(new_provider_b),
+            reserves_ctxt.owner.from_arcis(new_reserves),
+        )
+    }
+
+    /// Input for a constant-product swap
+    pub struct CpmmSwapInput {
+        /// Amount of the input mint sold, fee included
+        amount_in: u64,
+        /// Minimum amount of the output mint to receive (slippage protection)
+        min_amount_out: u64,
+        /// Sell `mint_a` for `mint_b` if true, `mint_b` for `mint_a` otherwise
+        a_to_b: bool,
+    }
+
+    /// Output of a constant-product swap
+    pub struct CpmmSwapOutput {
+        /// Whether the swap was applied
+        is_valid: bool,
+        /// Amount of the output mint received (zero if rejected)
+        amount_out: u64,
+        /// Fee kept by the pool, part of `amount_in` (zero if rejected)
+        fee: u64,
+    }
+
+    /// Swap against a pool's encrypted reserves
+    ///
+    /// The fee, `amount_in * fee_bps / 10_000` rounded down, stays in the
+    /// pool. The rest of the input is priced on the `x * y = k` curve:
+    /// `amount_out = reserve_out * in / (reserve_in + in)`, rounded down, so
+    /// `k` never decreases. The direction is encrypted, so observers cannot
+    /// tell which side was bought.
+    ///
+    /// The swap is applied only if the trader covers `amount_in`, both
+    /// reserves are non-zero, `amount_out` is non-zero and meets
+    /// `min_amount_out`, and no balance overflows. The trader's balances and
+    /// the reserves are otherwise returned unchanged. Per mint, the trader's
+    /// debit or credit always equals the pool's opposite change.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn swap_cpmm(
+        input_ctxt: Enc<Shared, CpmmSwapInput>,
+        trader_a_ctxt: Enc<Mxe, u64>,
+        trader_b_ctxt: Enc<Mxe, u64>,
+        reserves_ctxt: Enc<Mxe, PoolReserves>,
+        fee_bps: u16,
+    ) -> (
+        Enc<Shared, CpmmSwapOutput>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, PoolReserves>,
+    ) {
+        let input = input_ctxt.to_arcis();
+        let trader_a = trader_a_ctxt.to_arcis();
+        let trader_b = trader_b_ctxt.to_arcis();
+        let reserves = reserves_ctxt.to_arcis();
+
+        // Orient the trader's balances and the reserves as (in, out)
+        let (balance_in, balance_out, reserve_in, reserve_out) = if input.a_to_b {
+            (trader_a, trader_b, reserves.reserve_a, reserves.reserve_b)
+        } else {
+            (trader_b, trader_a, reserves.reserve_b, reserves.reserve_a)
+        };
+
+        // Widen before multiplying so large amounts cannot wrap
+        let fee = ((input.amount_in as u128) * (fee_bps as u128) / BPS_DENOMINATOR) as u64;
+        let net_in = (input.amount_in - fee) as u128;
+        let numerator = (reserve_out as u128) * net_in;
+        let denominator = (reserve_in as u128) + net_in;
+        // Only zero when the pool and the input are both empty, which is
+        // rejected below; avoid dividing by zero either way
+        let safe_denominator = if denominator == 0 { 1 } else { denominator };
+        let amount_out = (numerator / safe_denominator) as u64;
+
+        let has_funds = balance_in >= input.amount_in;
+        let pool_empty = reserve_in == 0 || reserve_out == 0;
+        let overflows =
+            input.amount_in > u64::MAX - reserve_in || amount_out > u64::MAX - balance_out;
+        let slippage_ok = amount_out > 0 && amount_out >= input.min_amount_out;
+        let is_valid = has_funds && !pool_empty && !overflows && slippage_ok;
+
+        let (new_balance_in, new_balance_out, new_reserve_in, new_reserve_out, paid, charged) =
+            if is_valid {
+                (
+                    balance_in - input.amount_in,
+                    balance_out + amount_out,
+                    reserve_in + input.amount_in,
+                    reserve_out - amount_out,
+                    amount_out,
+                    fee,
+                )
+            } else {
+                (balance_in, balance_out, reserve_in, reserve_out, 0, 0)
+            };
+
+        // Map (in, out) back onto (a, b)
+        let (new_trader_a, new_trader_b, new_reserve_a, new_reserve_b) = if input.a_to_b {
+            (
+                new_balance_in,
+                new_balance_out,
+                new_reserve_in,
+                new_reserve_out,
+            )
+        } else {
+            (
+                new_balance_out,
+                new_balance_in,
+                new_reserve_out,
+                new_reserve_in,
+            )
+        };
+
+        let output = CpmmSwapOutput {
+            is_valid,
+            amount_out: paid,
+            fee: charged,
+        };
+
+        (
+            input_ctxt.owner.from_arcis(output),
+            trader_a_ctxt.owner.from_arcis(new_trader_a),
+            trader_b_ctxt.owner.from_arcis(new_trader_b),
+            reserves_ctxt.owner.from_arcis(PoolReserves {
+                reserve_a: new_reserve_a,
+                reserve_b: new_reserve_b,
+            }),
+        )
+    }
+
+    /// Input for placing a limit order
+    pub struct OrderInput {
+        /// Buy the base mint with the quote mint if true, sell it otherwise
+        is_buy: bool,
+        /// Limit price, quote per base unit scaled by `PRICE_SCALE`
+        price: u64,
+        /// Order size in base units
+        size: u64,
+    }
+
+    /// MXE-encrypted state of a resting limit order
+    pub struct OrderState {
+        /// Side of the order
+        is_buy: bool,
+        /// Limit price, quote per base unit scaled by `PRICE_SCALE`
+        price: u64,
+        /// Size still unfilled, in base units
+        remaining: u64,
+        /// Funds held for the unfilled size: base for a sell, quote for a buy
+        escrow: u64,
+        /// Filled funds not yet paid out: quote for a sell, base for a buy
+        proceeds: u64,
+    }
+
+    /// Fill notice for the owner of a matched order
+    pub struct OrderFill {
+        /// Base units filled by this match (zero if the orders did not cross)
+        filled: u64,
+        /// Size still unfilled after the match
+        remaining: u64,
+        /// Proceeds waiting in the order after the match
+        proceeds: u64,
+    }
+
+    /// Escrow a limit order's funds and re-encrypt it for the MXE
+    ///
+    /// A sell escrows `size` of the base balance, a buy `size * price /
+    /// PRICE_SCALE` (rounded down) of the quote balance. Both balances are
+    /// read and written so the side stays hidden. If the balance does not
+    /// cover the escrow, or a buy's notional exceeds `u64::MAX`, the order is
+    /// stored empty and both balances are
+    /// returned unchanged. Returns whether the order was funded, encrypted
+    /// for the owner.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn place_order(
+        input_ctxt: Enc<Shared, OrderInput>,
+        base_balance_ctxt: Enc<Mxe, u64>,
+        quote_balance_ctxt: Enc<Mxe, u64>,
+    ) -> (
+        Enc<Shared, bool>,
+        Enc<Mxe, OrderState>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+    ) {
+        let input = input_ctxt.to_arcis();
+        let base_balance = base_balance_ctxt.to_arcis();
+        let quote_balance = quote_balance_ctxt.to_arcis();
+
+        // Widen before multiplying so a large buy cannot wrap
+        let notional_wide = (input.size as u128) * (input.price as u128) / PRICE_SCALE;
+        let notional_overflows = input.is_buy && notional_wide > u64::MAX as u128;
+        let escrow = if input.is_buy {
+            notional_wide as u64
+        } else {
+            input.size
+        };
+        let available = if input.is_buy {
+            quote_balance
+        } else {
+            base_balance
+        };
+        let is_valid = available >= escrow && !notional_overflows;
+
+        let (remaining, held) = if is_valid {
+            (input.size, escrow)
+        } else {
+            (0, 0)
+        };
+        let (new_base, new_quote) = if input.is_buy {
+            (base_balance, quote_balance - held)
+        } else {
+            (base_balance - held, quote_balance)
+        };
+
+        (
+            input_ctxt.owner.from_arcis(is_valid),
+            Mxe::get().from_arcis(OrderState {
+                is_buy: input.is_buy,
+                price: input.price,
+                remaining,
+                escrow: held,
+                proceeds: 0,
+            }),
+            base_balance_ctxt.owner.from_arcis(new_base),
+            quote_balance_ctxt.owner.from_arcis(new_quote),
+        )
+    }
+
+    /// Match two resting limit orders
+    ///
+    /// The orders cross when exactly one of them buys, both have size left
+    /// and the buy price is at least the sell price. The fill is the smaller
+    /// remaining size and trades at the sell price: the seller's escrow pays
+    /// the filled base into the buyer's proceeds, and the buyer's escrow pays
+    /// `filled * sell_price / PRICE_SCALE` (rounded down) of quote into the
+    /// seller's proceeds. A match that would overflow the seller's proceeds
+    /// is treated as not crossing, and both orders are returned unchanged.
+    ///
+    /// Nothing is revealed. Each owner receives their fill encrypted to
+    /// their own key, and whether the orders crossed is encrypted to the
+    /// matcher. The side, price and sizes stay with the MXE.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn match_orders(
+        order_a_ctxt: Enc<Mxe, OrderState>,
+        owner_a_key: ArcisX25519Pubkey,
+        order_b_ctxt: Enc<Mxe, OrderState>,
+        owner_b_key: ArcisX25519Pubkey,
+        matcher_key: ArcisX25519Pubkey,
+    ) -> (
+        Enc<Mxe, OrderState>,
+        Enc<Mxe, OrderState>,
+        Enc<Shared, OrderFill>,
+        Enc<Shared, OrderFill>,
+        Enc<Shared, bool>,
+    ) {
+        let order_a = order_a_ctxt.to_arcis();
+        let order_b = order_b_ctxt.to_arcis();
+
+        let (buy, sell) = if order_a.is_buy {
+            (order_a, order_b)
+        } else {
+            (order_b, order_a)
+        };
+        let smaller = if order_a.remaining < order_b.remaining {
+            order_a.remaining
+        } else {
+            order_b.remaining
+        };
+        // Checked against the buyer's escrow below, so saturating is safe
+        let cost_wide = (smaller as u128) * (sell.price as u128) / PRICE_SCALE;
+        let cost = if cost_wide > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            cost_wide as u64
+        };
+
+        let opposite_sides = order_a.is_buy != order_b.is_buy;
+        let has_size = order_a.remaining > 0 && order_b.remaining > 0;
+        let covered = cost <= buy.escrow && smaller <= sell.escrow;
+        let proceeds_overflow = cost > u64::MAX - sell.proceeds;
+        let matched = opposite_sides
+            && has_size
+            && buy.price >= sell.price
+            && covered
+            && !proceeds_overflow;
+
+        let (filled, paid) = if matched { (smaller, cost) } else { (0, 0) };
+
+        let new_buy = OrderState {
+            is_buy: buy.is_buy,
+            price: buy.price,
+            remaining: buy.remaining - filled,
+            escrow: buy.escrow - paid,
+            proceeds: buy.proceeds + filled,
+        };
+        let new_sell = OrderState {
+            is_buy: sell.is_buy,
+            price: sell.price,
+            remaining: sell.remaining - filled,
+            escrow: sell.escrow - filled,
+            proceeds: sell.proceeds + paid,
+        };
+        // Only a crossed match writes the orders; otherwise they keep their
+        // exact state, including any same-side pair
+        let (new_order_a, new_order_b) = if !matched {
+            (order_a, order_b)
+        } else if order_a.is_buy {
+            (new_buy, new_sell)
+        } else {
+            (new_sell, new_buy)
+        };
+
+        let fill_a = OrderFill {
+            filled,
+            remaining: new_order_a.remaining,
+            proceeds: new_order_a.proceeds,
+        };
+        let fill_b = OrderFill {
+            filled,
+            remaining: new_order_b.remaining,
+            proceeds: new_order_b.proceeds,
+        };
+
+        (
+            order_a_ctxt.owner.from_arcis(new_order_a),
+            order_b_ctxt.owner.from_arcis(new_order_b),
+            Shared::new(owner_a_key).from_arcis(fill_a),
+            Shared::new(owner_b_key).from_arcis(fill_b),
+            Shared::new(matcher_key).from_arcis(matched),
+        )
+    }
+
+    /// Pay an order's proceeds into its owner's balances
+    ///
+    /// With `close`, the escrow still held for the unfilled size is refunded
+    /// too and the order is emptied. Both balances are read and written so
+    /// the side stays hidden. Applied only if neither balance overflows;
+    /// otherwise everything is returned unchanged. Reveals whether it was
+    /// applied, so the program knows whether a closing order can be deleted.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn settle_order(
+        order_ctxt: Enc<Mxe, OrderState>,
+        base_balance_ctxt: Enc<Mxe, u64>,
+        quote_balance_ctxt: Enc<Mxe, u64>,
+        close: bool,
+    ) -> (Enc<Mxe, OrderState>, Enc<Mxe, u64>, Enc<Mxe, u64>, bool) {
+        let order = order_ctxt.to_arcis();
+        let base_balance = base_balance_ctxt.to_arcis();
+        let quote_balance = quote_balance_ctxt.to_arcis();
+
+        let refund = if close { order.escrow } else { 0 };
+        let (base_credit, quote_credit) = if order.is_buy {
+            (order.proceeds, refund)
+        } else {
+            (refund, order.proceeds)
+        };
+        let settled =
+            base_credit <= u64::MAX - base_balance && quote_credit <= u64::MAX - quote_balance;
+
+        let (new_order, new_base, new_quote) = if settled {
+            (
+                OrderState {
+                    is_buy: order.is_buy,
+                    price: order.price,
+                    remaining: if close { 0 } else { order.remaining },
+                    escrow: order.escrow - refund,
+                    proceeds: 0,
+                },
+                base_balance + base_credit,
+                quote_balance + quote_credit,
+            )
+        } else {
+            (order, base_balance, quote_balance)
+        };
+
+        (
+            order_ctxt.owner.from_arcis(new_order),
+            base_balance_ctxt.owner.from_arcis(new_base),
+            quote_balance_ctxt.owner.from_arcis(new_quote),
+            settled.reveal(),
+        )
+    }
+
+    /// Number of orders cleared together by `batch_auction`
+    ///
+    /// Kept small so the callback, which writes every order, fits in a
+    /// single transaction.
+    pub const AUCTION_SIZE: usize = 4;
+
+    /// Fill notice for one order of a batch auction
+    pub struct AuctionFill {
+        /// Base units filled at the clearing price (zero if the order did not clear)
+        filled: u64,
+        /// Uniform clearing price, quote per base unit scaled by `PRICE_SCALE`
+        /// (zero if nothing cleared)
+        clearing_price: u64,
+        /// Size still unfilled after the auction
+        remaining: u64,
+        /// Proceeds waiting to be settled, in the mint being received
+        proceeds: u64,
+    }
+
+    /// Clear a batch of resting limit orders at one uniform price
+    ///
+    /// Every order's limit price is a candidate. At each candidate, demand is
+    /// the size of the buys priced at or above it and supply the size of the
+    /// sells priced at or below it. The clearing price is the candidate with
+    /// the largest matched volume, `min(demand, supply)`, taking the lowest
+    /// price on a tie. Every order trades at that price, so ordering within
+    /// the batch cannot be exploited for a better one.
+    ///
+    /// The short side of the book is filled completely. The long side is
+    /// filled in slot order until the volume runs out, so the base units
+    /// bought always equal the base units sold.
+    ///
+    /// Fills settle between the orders like `match_orders`: each buy pays
+    /// `filled * clearing_price / PRICE_SCALE`, rounded down, out of its
+    /// escrow, and the sells share exactly what the buys paid, the last
+    /// filled sell taking the rounding remainder. The auction does not clear
+    /// if any escrow falls short or any proceeds would overflow.
+    ///
+    /// Whether anything cleared is encrypted to the matcher. Each owner
+    /// receives their fill, the clearing price, their remaining size and
+    /// their proceeds encrypted to their own key.
+    #[allow(clippy::type_complexity)]
+    #[instruction]
+    pub fn batch_auction(
+        orders_ctxt: [Enc<Mxe, OrderState>; AUCTION_SIZE],
+        owner_keys: [ArcisX25519Pubkey; AUCTION_SIZE],
+        matcher_key: ArcisX25519Pubkey,
+    ) -> (
+        [Enc<Mxe, OrderState>; AUCTION_SIZE],
+        [Enc<Shared, AuctionFill>; AUCTION_SIZE],
+        Enc<Shared, bool>,
+    ) {
+        let mut is_buy = [false; AUCTION_SIZE];
+        let mut prices = [0u64; AUCTION_SIZE];
+        let mut remaining = [0u64; AUCTION_SIZE];
+        let mut escrow = [0u64; AUCTION_SIZE];
+        let mut proceeds = [0u64; AUCTION_SIZE];
+        for i in 0..AUCTION_SIZE {
+            let order = orders_ctxt[i].to_arcis();
+            is_buy[i] = order.is_buy;
+            prices[i] = order.price;
+            remaining[i] = order.remaining;
+            escrow[i] = order.escrow;
+            proceeds[i] = order.proceeds;
+        }
+
+        // Sizes are summed in u128 so a full batch cannot wrap
+        let mut clearing_price = 0u64;
+        let mut volume = 0u128;
+        for c in 0..AUCTION_SIZE {
+            let candidate = prices[c];
+            let mut demand = 0u128;
+            let mut supply = 0u128;
+            for i in 0..AUCTION_SIZE {
+                let buys = is_buy[i] && prices[i] >= candidate;
+                let sells = !is_buy[i] && prices[i] <= candidate;
+                demand += if buys { remaining[i] as u128 } else { 0 };
+                supply += if sells { remaining[i] as u128 } else { 0 };
+            }
+            let matched = if demand < supply { demand } else { supply };
+
+            let better = matched > volume || (matched == volume && candidate < clearing_price);
+            clearing_price = if better { candidate } else { clearing_price };
+            volume = if better { matched } else { volume };
+        }
+
+        let mut buys_left = volume;
+        let mut sells_left = volume;
+        let mut filled = [0u64; AUCTION_SIZE];
+        for i in 0..AUCTION_SIZE {
+            let eligible = if is_buy[i] {
+                prices[i] >= clearing_price
+            } else {
+                prices[i] <= clearing_price
+            };
+            let left = if is_buy[i] { buys_left } else { sells_left };
+            let wanted = if eligible { remaining[i] as u128 } else { 0 };
+            let fill = if wanted < left { wanted } else { left };
+            (buys_left, sells_left) = if is_buy[i] {
+                (buys_left - fill, sells_left)
+            } else {
+                (buys_left, sells_left - fill)
+            };
+            filled[i] = fill as u64;
+        }
+
+        let mut paid = [0u128; AUCTION_SIZE];
+        let mut total_paid = 0u128;
+        let mut covered = true;
+        for i in 0..AUCTION_SIZE {
+            let cost = (filled[i] as u128) * (clearing_price as u128) / PRICE_SCALE;
+            paid[i] = if is_buy[i] { cost } else { 0 };
+            total_paid += paid[i];
+            let spent = if is_buy[i] { cost } else { filled[i] as u128 };
+            covered = covered && spent <= escrow[i] as u128;
+        }
+
+        // Walk the slots backwards to find the last sell that filled
+        let mut later_sell = false;
+        let mut last_sell = [false; AUCTION_SIZE];
+        for k in 0..AUCTION_SIZE {
+            let i = AUCTION_SIZE - 1 - k;
+            let sells = !is_buy[i] && filled[i] > 0;
+            last_sell[i] = sells && !later_sell;
+            later_sell = later_sell || sells;
+        }
+
+        let mut paid_left = total_paid;
+        let mut received = [0u128; AUCTION_SIZE];
+        for i in 0..AUCTION_SIZE {
+            let share = (filled[i] as u128) * (clearing_price as u128) / PRICE_SCALE;
+            let capped = if share < paid_left { share } else { paid_left };
+            let take = if last_sell[i] { paid_left } else { capped };
+            received[i] = if is_buy[i] { filled[i] as u128 } else { take };
+            paid_left -= if is_buy[i] { 0 } else { take };
+        }
+
+        let mut fits = true;
+        let mut new_proceeds_wide = [0u128; AUCTION_SIZE];
+        for i in 0..AUCTION_SIZE {
+            new_proceeds_wide[i] = proceeds[i] as u128 + received[i];
+            fits = fits && new_proceeds_wide[i] <= u64::MAX as u128;
+        }
+        let settles = covered && fits;
+        let cleared = volume > 0 && settles;
+
+        let mut new_filled = [0u64; AUCTION_SIZE];
+        let mut new_remaining = [0u64; AUCTION_SIZE];
+        let mut new_escrow = [0u64; AUCTION_SIZE];
+        let mut new_proceeds = [0u64; AUCTION_SIZE];
+        for i in 0..AUCTION_SIZE {
+            let spent = if is_buy[i] { paid[i] as u64 } else { filled[i] };
+            new_filled[i] = if settles { filled[i] } else { 0 };
+            new_remaining[i] = if settles { remaining[i] - filled[i] } else { remaining[i] };
+            new_escrow[i] = if settles { escrow[i] - spent } else { escrow[i] };
+            new_proceeds[i] = if settles {
+                new_proceeds_wide[i] as u64
+            } else {
+                proceeds[i]
+            };
+        }
+        let fill_price = if cleared { clearing_price } else { 0 };
+
+        (
+            [
+                Mxe::get().from_arcis(OrderState {
+                    is_buy: is_buy[0],
+                    price: prices[0],
+                    remaining: new_remaining[0],
+                    escrow: new_escrow[0],
+                    proceeds: new_proceeds[0],
+                }),
+                Mxe::get().from_arcis(OrderState {
+                    is_buy: is_buy[1],
+                    price: prices[1],
+                    remaining: new_remaining[1],
+                    escrow: new_escrow[1],
+                    proceeds: new_proceeds[1],
+                }),
+                Mxe::get().from_arcis(OrderState {
+                    is_buy: is_buy[2],
+                    price: prices[2],
+                    remaining: new_remaining[2],
+                    escrow: new_escrow[2],
+                    proceeds: new_proceeds[2],
+                }),
+                Mxe::get().from_arcis(OrderState {
+                    is_buy: is_buy[3],
+                    price: prices[3],
+                    remaining: new_remaining[3],
+                    escrow: new_escrow[3],
+                    proceeds: new_proceeds[3],
+                }),
+            ],
+            [
+                Shared::new(owner_keys[0]).from_arcis(AuctionFill {
+                    filled: new_filled[0],
+                    clearing_price: fill_price,
+                    remaining: new_remaining[0],
+                    proceeds: new_proceeds[0],
+                }),
+                Shared::new(owner_keys[1]).from_arcis(AuctionFill {
+                    filled: new_filled[1],
+                    clearing_price: fill_price,
+                    remaining: new_remaining[1],
+                    proceeds: new_proceeds[1],
+                }),
+                Shared::new(owner_keys[2]).from_arcis(AuctionFill {
+                    filled: new_filled[2],
+                    clearing_price: fill_price,
+                    remaining: new_remaining[2],
+                    proceeds: new_proceeds[2],
+                }),
+                Shared::new(owner_keys[3]).from_arcis(AuctionFill {
+                    filled: new_filled[3],
+                    clearing_price: fill_price,
+                    remaining: new_remaining[3],
+                    proceeds: new_proceeds[3],
+                }),
+            ],
+            Shared::new(matcher_key).from_arcis(cleared),
+        )
+    }
+
+    /// `SealedBids::winner` while no bid has won, only a positive bid can
+    pub const NO_BID: u8 = u8::MAX;
+
+    /// MXE-encrypted standing of a sealed-bid auction: its two best bids
+    pub struct SealedBids {
+        /// Highest bid so far
+        highest: u64,
+        /// Second-highest bid so far (zero with fewer than two bids)
+        second: u64,
+        /// Submission index of the highest bid, `NO_BID` before one
+        winner: u8,
+    }
+
+    /// Open a sealed-bid auction with no bids, escrowing its lot
+    ///
+    /// Moves the public `lot_amount` out of the seller's lot-mint balance.
+    /// Whether the balance covered it is revealed so bidders know the lot is
+    /// held; if not, the balance is returned unchanged.
+    #[instruction]
+    pub fn init_auction(
+        lot_balance_ctxt: Enc<Mxe, u64>,
+        lot_amount: u64,
+    ) -> (Enc<Mxe, SealedBids>, Enc<Mxe, u64>, bool) {
+        let lot_balance = lot_balance_ctxt.to_arcis();
+
+        let escrowed = lot_balance >= lot_amount;
+        let new_lot_balance = if escrowed {
+            lot_balance - lot_amount
+        } else {
+            lot_balance
+        };
+
+        (
+            Mxe::get().from_arcis(SealedBids {
+                highest: 0,
+                second: 0,
+                winner: NO_BID,
+            }),
+            lot_balance_ctxt.owner.from_arcis(new_lot_balance),
+            escrowed.reveal(),
+        )
+    }
+
+    /// Input for a sealed bid
+    pub struct BidInput {
+        /// Bid in the auction's bid mint
+        amount: u64,
+    }
+
+    /// Escrow a sealed bid and fold it into an auction's standing
+    ///
+    /// The bid is moved out of the bidder's bid-mint balance into its own
+    /// escrow. A bid the balance cannot cover, or below the public
+    /// `reserve_price`, is folded in as zero, so it never wins, and leaves
+    /// the balance unchanged; only the bidder learns which happened.
+    /// `bid_index` is the bid's position in submission order. Ties go to the
+    /// earlier bid.
+    #[instruction]
+    pub fn submit_bid(
+        input_ctxt: Enc<Shared, BidInput>,
+        bids_ctxt: Enc<Mxe, SealedBids>,
+        balance_ctxt: Enc<Mxe, u64>,
+        bid_index: u8,
+        reserve_price: u64,
+    ) -> (
+        Enc<Shared, bool>,
+        Enc<Mxe, SealedBids>,
+        Enc<Mxe, u64>,
+        Enc<Mxe, u64>,
+    ) {
+        let input = input_ctxt.to_arcis();
+        let bids = bids_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+
+        let is_valid = input.amount <= balance && input.amount >= reserve_price;
+        let amount = if is_valid { input.amount } else { 0 };
+
+        let wins = amount > bids.highest;
+        let (highest, second, winner) = if wins {
+            (amount, bids.highest, bid_index)
+        } else if amount > bids.second {
+            (bids.highest, amount, bids.winner)
+        } else {
+            (bids.highest, bids.second, bids.winner)
+        };
+
+        (
+            input_ctxt.owner.from_arcis(is_valid),
+            bids_ctxt.owner.from_arcis(SealedBids {
+                highest,
+                second,
+                winner,
+            }),
+            Mxe::get().from_arcis(amount),
+            balance_ctxt.owner.from_arcis(balance - amount),
+        )
+    }
+
+    /// Close a sealed-bid auction
+    ///
+    /// Reveals only the winning bid's index and the clearing price: the
+    /// highest bid, or the second-highest when `second_price` is set
+    /// (Vickrey), but never less than the public `reserve_price`. Every other
+    /// bid stays hidden. The index is `NO_BID`, and the price zero, if no
+    /// bid met the reserve.
+    #[instruction]
+    pub fn resolve_auction(
+        bids_ctxt: Enc<Mxe, SealedBids>,
+        second_price: bool,
+        reserve_price: u64,
+    ) -> (u8, u64) {
+        let bids = bids_ctxt.to_arcis();
+        let bid_price = if second_price {
+            bids.second
+        } else {
+            bids.highest
+        };
+        let price = if bids.winner == NO_BID {
+            0
+        } else if bid_price > reserve_price {
+            bid_price
+        } else {
+            reserve_price
+        };
+        (bids.winner.reveal(), price.reveal())
+    }
+
+    /// Pay out a bid's escrow once its auction is resolved
+    ///
+    /// The winning bid (`won`) pays the public `price` and is credited the
+    /// `lot_amount` in the lot mint; the rest of its escrow is refunded. A
+    /// losing bid's whole escrow is refunded. Both balances are left
+    /// unchanged, and `settled` is false, if the escrow cannot cover the
+    /// price or a credit would overflow.
+    #[instruction]
+    pub fn settle_bid(
+        escrow_ctxt: Enc<Mxe, u64>,
+        bid_balance_ctxt: Enc<Mxe, u64>,
+        lot_balance_ctxt: Enc<Mxe, u64>,
+        won: bool,
+        price: u64,
+        lot_amount: u64,
+    ) -> (Enc<Mxe, u64>, Enc<Mxe, u64>, bool) {
+        let escrow = escrow_ctxt.to_arcis();
+        let bid_balance = bid_balance_ctxt.to_arcis();
+        let lot_balance = lot_balance_ctxt.to_arcis();
+
+        let paid = if won { price } else { 0 };
+        let lot_credit = if won { lot_amount } else { 0 };
+        let covered = escrow >= paid;
+        let refund = if covered { escrow - paid } else { 0 };
+
+        let new_bid_wide = bid_balance as u128 + refund as u128;
+        let new_lot_wide = lot_balance as u128 + lot_credit as u128;
+        let settled =
+            covered && new_bid_wide <= u64::MAX as u128 && new_lot_wide <= u64::MAX as u128;
+
+        let (new_bid_balance, new_lot_balance) = if settled {
+            (new_bid_wide as u64, new_lot_wide as u64)
+        } else {
+            (bid_balance, lot_balance)
+        };
+
+        (
+            bid_balance_ctxt.owner.from_arcis(new_bid_balance),
+            lot_balance_ctxt.owner.from_arcis(new_lot_balance),
+            settled.reveal(),
+        )
+    }
+
+    /// Pay the seller of a resolved auction
+    ///
+    /// A sold lot credits the public `price` to the seller's bid-mint
+    /// balance; an unsold one returns the escrowed `lot_amount` to their
+    /// lot-mint balance. Both balances are left unchanged, and `settled` is
+    /// false, if the credit would overflow.
+    #[instruction]
+    pub fn settle_auction(
+        bid_balance_ctxt: Enc<Mxe, u64>,
+        lot_balance_ctxt: Enc<Mxe, u64>,
+        sold: bool,
+        price: u64,
+        lot_amount: u64,
+    ) -> (Enc<Mxe, u64>, Enc<Mxe, u64>, bool) {
+        let bid_balance = bid_balance_ctxt.to_arcis();
+        let lot_balance = lot_balance_ctxt.to_arcis();
+
+        let bid_credit = if sold { price } else { 0 };
+        let lot_credit = if sold { 0 } else { lot_amount };
+
+        let new_bid_wide = bid_balance as u128 + bid_credit as u128;
+        let new_lot_wide = lot_balance as u128 + lot_credit as u128;
+        let settled = new_bid_wide <= u64::MAX as u128 && new_lot_wide <= u64::MAX as u128;
+
+        let (new_bid_balance, new_lot_balance) = if settled {
+            (new_bid_wide as u64, new_lot_wide as u64)
+        } else {
+            (bid_balance, lot_balance)
+        };
+
+        (
+            bid_balance_ctxt.owner.from_arcis(new_bid_balance),
+            lot_balance_ctxt.owner.from_arcis(new_lot_balance),
+            settled.reveal(),
+        )
+    }
+
+    /// Re-encrypt an MXE-held balance to an auditor's key
+    ///
+    /// Nothing is revealed: the result can only be read with the secret for
+    /// `auditor_key`, not by the balance holder or anyone watching the chain.
+    #[instruction]
+    pub fn disclose_balance(
+        balance_ctxt: Enc<Mxe, u64>,
+        auditor_key: ArcisX25519Pubkey,
+    ) -> Enc<Shared, u64> {
+        let balance = balance_ctxt.to_arcis();
+        Shared::new(auditor_key).from_arcis(balance)
+    }
 }
@@ -1,19 +1,124 @@
+//! SIP Private Transfer Program
+//!
+//! Solana program that queues encrypted computations to Arcium MXE.
+//! Works with the `encrypted-ixs` circuits for:
+//! - Encrypted balances (MXE-owned, stored per user and mint on-chain)
+//! - Deposits and withdrawals between SPL tokens and encrypted balances
+//! - Private transfers (balance hidden)
+//! - Balance checks (threshold validation)
+//! - Confidential swaps (DEX with hidden amounts)
+//!
+//! @see https://github.com/sip-protocol/sip-mobile/issues/73
+
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
 use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::CallbackAccount;
 
-/// SIP Private Transfer Program
-///
-/// Solana program that queues encrypted computations to Arcium MXE.
-/// Works with the `encrypted-ixs` circuits for:
-/// - Private transfers (balance hidden)
-/// - Balance checks (threshold validation)
-/// - Confidential swaps (DEX with hidden amounts)
-///
-/// @see https://github.com/sip-protocol/sip-mobile/issues/73
+pub const COMP_DEF_OFFSET_CREATE_BALANCE: u32 = comp_def_offset("create_balance");
+pub const COMP_DEF_OFFSET_DEPOSIT: u32 = comp_def_offset("deposit");
+pub const COMP_DEF_OFFSET_WITHDRAW: u32 = comp_def_offset("withdraw");
+pub const COMP_DEF_OFFSET_CLAIM_INCOMING: u32 = comp_def_offset("claim_incoming");
+pub const COMP_DEF_OFFSET_PRIVATE_TRANSFER: u32 = comp_def_offset("private_transfer");
+pub const COMP_DEF_OFFSET_BATCH_TRANSFER: u32 = comp_def_offset("batch_transfer");
+pub const COMP_DEF_OFFSET_SWEEP_FEE: u32 = comp_def_offset("sweep_fee");
+pub const COMP_DEF_OFFSET_CHECK_BALANCE: u32 = comp_def_offset("check_balance");
+pub const COMP_DEF_OFFSET_VALIDATE_SWAP: u32 = comp_def_offset("validate_swap");
+pub const COMP_DEF_OFFSET_VALIDATE_ORACLE_SWAP: u32 = comp_def_offset("validate_oracle_swap");
+pub const COMP_DEF_OFFSET_INIT_POOL: u32 = comp_def_offset("init_pool");
+pub const COMP_DEF_OFFSET_ADD_LIQUIDITY: u32 = comp_def_offset("add_liquidity");
+pub const COMP_DEF_OFFSET_REMOVE_LIQUIDITY: u32 = comp_def_offset("remove_liquidity");
+pub const COMP_DEF_OFFSET_SWAP_CPMM: u32 = comp_def_offset("swap_cpmm");
+pub const COMP_DEF_OFFSET_PLACE_ORDER: u32 = comp_def_offset("place_order");
+pub const COMP_DEF_OFFSET_MATCH_ORDERS: u32 = comp_def_offset("match_orders");
+pub const COMP_DEF_OFFSET_SETTLE_ORDER: u32 = comp_def_offset("settle_order");
+pub const COMP_DEF_OFFSET_BATCH_AUCTION: u32 = comp_def_offset("batch_auction");
+pub const COMP_DEF_OFFSET_INIT_AUCTION: u32 = comp_def_offset("init_auction");
+pub const COMP_DEF_OFFSET_SUBMIT_BID: u32 = comp_def_offset("submit_bid");
+pub const COMP_DEF_OFFSET_RESOLVE_AUCTION: u32 = comp_def_offset("resolve_auction");
+pub const COMP_DEF_OFFSET_SETTLE_BID: u32 = comp_def_offset("settle_bid");
+pub const COMP_DEF_OFFSET_SETTLE_AUCTION: u32 = comp_def_offset("settle_auction");
+pub const COMP_DEF_OFFSET_DISCLOSE_BALANCE: u32 = comp_def_offset("disclose_balance");
+
+/// PDA seed for `EncryptedBalanceAccount`
+pub const BALANCE_SEED: &[u8] = b"balance";
+/// PDA seed for the per-mint token vault
+pub const VAULT_SEED: &[u8] = b"vault";
+/// PDA seed for `ComputationRequest`
+pub const COMPUTATION_REQUEST_SEED: &[u8] = b"computation";
+/// PDA seed for the per-mint `Treasury`
+pub const TREASURY_SEED: &[u8] = b"treasury";
+/// PDA seed for a transfer's `FeeRecord`
+pub const FEE_RECORD_SEED: &[u8] = b"fee";
+/// PDA seed for the global `ProgramConfig`
+pub const CONFIG_SEED: &[u8] = b"config";
+/// PDA seed for a swap pair's `PriceFeed`
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+/// PDA seed for a mint pair's constant-product `Pool`
+pub const POOL_SEED: &[u8] = b"pool";
+/// PDA seed for a `LimitOrder`
+pub const ORDER_SEED: &[u8] = b"order";
+/// PDA seed for a `SealedAuction`
+pub const AUCTION_SEED: &[u8] = b"auction";
+/// PDA seed for a bidder's `SealedBid`
+pub const BID_SEED: &[u8] = b"bid";
+/// PDA seed for a `BalanceDisclosure`
+pub const DISCLOSURE_SEED: &[u8] = b"disclosure";
+
+/// Highest protocol fee a treasury can charge, in basis points (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Recipient slots in a `batch_transfer`, matching `circuits::BATCH_SIZE`
+pub const BATCH_SIZE: usize = 4;
+
+/// Order slots in a `batch_auction`, matching `circuits::AUCTION_SIZE`
+pub const AUCTION_SIZE: usize = 4;
+
+/// Bids a `SealedAuction` accepts; every bid index stays below `NO_BID`
+pub const MAX_AUCTION_BIDS: usize = 32;
 
-const COMP_DEF_OFFSET_PRIVATE_TRANSFER: u32 = comp_def_offset("private_transfer");
-const COMP_DEF_OFFSET_CHECK_BALANCE: u32 = comp_def_offset("check_balance");
-const COMP_DEF_OFFSET_VALIDATE_SWAP: u32 = comp_def_offset("validate_swap");
+/// Winner index of a sealed-bid auction with no positive bid, matching `circuits::NO_BID`
+pub const NO_BID: u8 = u8::MAX;
+
+/// Fixed-point scale of `PriceFeed::price`, matching `circuits::PRICE_SCALE`
+pub const PRICE_SCALE: u64 = 1_000_000_000;
+
+/// Slots after an update before a price is too stale to swap against (~1 minute)
+pub const MAX_PRICE_AGE_SLOTS: u64 = 150;
+
+/// `ProgramConfig::computation_timeout_slots` set by `initialize_config` (~10 minutes)
+pub const DEFAULT_COMPUTATION_TIMEOUT_SLOTS: u64 = 1_500;
+
+/// Shortest timeout `set_computation_timeout` accepts (~1 minute), so a
+/// healthy cluster always has time to call back
+pub const MIN_COMPUTATION_TIMEOUT_SLOTS: u64 = 150;
+
+/// Most accounts one computation locks, reached by a `batch_transfer` that
+/// charges a fee
+pub const MAX_LOCKED_ACCOUNTS: usize = 6;
+
+/// Byte offset of `EncryptedBalanceAccount::encrypted_balance` (after the discriminator)
+const ENCRYPTED_BALANCE_OFFSET: u32 = 8;
+/// Length of a single `Enc<Mxe, u64>` ciphertext
+const ENCRYPTED_BALANCE_LEN: u32 = 32;
+/// Byte offset of `EncryptedBalanceAccount::encrypted_incoming`, after the balance and its nonce
+const INCOMING_BALANCE_OFFSET: u32 = 56;
+/// Byte offset of `FeeRecord::encrypted_fee` (after the discriminator)
+const FEE_RECORD_OFFSET: u32 = 8;
+/// Byte offset of `Pool::encrypted_reserves` (after the discriminator)
+const POOL_RESERVES_OFFSET: u32 = 8;
+/// Length of an `Enc<Mxe, PoolReserves>`, one ciphertext per reserve
+const POOL_RESERVES_LEN: u32 = 64;
+/// Byte offset of `LimitOrder::encrypted_state` (after the discriminator)
+const ORDER_STATE_OFFSET: u32 = 8;
+/// Length of an `Enc<Mxe, OrderState>`: side, price, remaining size, escrow and proceeds
+const ORDER_STATE_LEN: u32 = 160;
+/// Byte offset of `SealedBid::encrypted_escrow` (after the discriminator)
+const BID_ESCROW_OFFSET: u32 = 8;
+/// Byte offset of `SealedAuction::encrypted_bids` (after the discriminator)
+const SEALED_BIDS_OFFSET: u32 = 8;
+/// Length of an `Enc<Mxe, SealedBids>`: highest, second-highest and winner
+const SEALED_BIDS_LEN: u32 = 96;
 
 declare_id!("S1P5q5497A6oRCUutUFb12LkNQynTNoEyRyUvotmcX9");
 
@@ -21,10 +126,107 @@ declare_id!("S1P5q5497A6oRCUutUFb12LkNQynTNoEyRyUvotmcX9");
 pub mod sip_arcium_transfer {
     use super::*;
 
+    // =========================================================================
+    // ADMIN
+    // =========================================================================
+
+    /// Create the global config with the payer as admin
+    ///
+    /// Callable once, and only by the program's upgrade authority.
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.payer.key();
+        config.pending_admin = None;
+        config.paused = false;
+        config.computation_timeout_slots = DEFAULT_COMPUTATION_TIMEOUT_SLOTS;
+        config.bump = ctx.bumps.config;
+        Ok(())
+    }
+
+    /// Pause or resume queueing new computations (admin only)
+    ///
+    /// While paused every queue instruction is rejected except the exits:
+    /// `withdraw`, `claim_incoming`, `sweep_fee`, `remove_liquidity`,
+    /// `settle_order`, `settle_bid` and `settle_auction`.
+    /// Callbacks for computations already in flight still land, and
+    /// `reclaim_expired_computation` still works.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.config.paused = paused;
+
+        emit!(PauseUpdatedEvent { paused });
+
+        Ok(())
+    }
+
+    /// Set how many slots after queueing a computation may be reclaimed (admin only)
+    ///
+    /// Applies to computations already in flight as well as new ones.
+    pub fn set_computation_timeout(
+        ctx: Context<SetComputationTimeout>,
+        timeout_slots: u64,
+    ) -> Result<()> {
+        require!(
+            timeout_slots >= MIN_COMPUTATION_TIMEOUT_SLOTS,
+            ErrorCode::ComputationTimeoutTooShort
+        );
+        ctx.accounts.config.computation_timeout_slots = timeout_slots;
+
+        emit!(ComputationTimeoutUpdatedEvent { timeout_slots });
+
+        Ok(())
+    }
+
+    /// Propose a new admin, who takes over once they call `accept_admin`
+    ///
+    /// Replaces any earlier proposal.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.config.pending_admin = Some(new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let previous_admin = config.admin;
+        config.admin = ctx.accounts.pending_admin.key();
+        config.pending_admin = None;
+
+        emit!(AdminTransferredEvent {
+            previous_admin,
+            new_admin: config.admin,
+        });
+
+        Ok(())
+    }
+
     // =========================================================================
     // INITIALIZATION
     // =========================================================================
 
+    /// Initialize the create_balance computation definition
+    pub fn init_create_balance_comp_def(ctx: Context<InitCreateBalanceCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the deposit computation definition
+    pub fn init_deposit_comp_def(ctx: Context<InitDepositCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the withdraw computation definition
+    pub fn init_withdraw_comp_def(ctx: Context<InitWithdrawCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the claim_incoming computation definition
+    pub fn init_claim_incoming_comp_def(ctx: Context<InitClaimIncomingCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
     /// Initialize the private_transfer computation definition
     pub fn init_private_transfer_comp_def(
         ctx: Context<InitPrivateTransferCompDef>,
@@ -33,6 +235,18 @@ pub mod sip_arcium_transfer {
         Ok(())
     }
 
+    /// Initialize the batch_transfer computation definition
+    pub fn init_batch_transfer_comp_def(ctx: Context<InitBatchTransferCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the sweep_fee computation definition
+    pub fn init_sweep_fee_comp_def(ctx: Context<InitSweepFeeCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
     /// Initialize the check_balance computation definition
     pub fn init_check_balance_comp_def(ctx: Context<InitCheckBalanceCompDef>) -> Result<()> {
         init_comp_def(ctx.accounts, None, None)?;
@@ -45,48 +259,195 @@ pub mod sip_arcium_transfer {
         Ok(())
     }
 
+    /// Initialize the validate_oracle_swap computation definition
+    pub fn init_validate_oracle_swap_comp_def(
+        ctx: Context<InitValidateOracleSwapCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the init_pool computation definition
+    pub fn init_init_pool_comp_def(ctx: Context<InitInitPoolCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the add_liquidity computation definition
+    pub fn init_add_liquidity_comp_def(ctx: Context<InitAddLiquidityCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the remove_liquidity computation definition
+    pub fn init_remove_liquidity_comp_def(ctx: Context<InitRemoveLiquidityCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the swap_cpmm computation definition
+    pub fn init_swap_cpmm_comp_def(ctx: Context<InitSwapCpmmCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the place_order computation definition
+    pub fn init_place_order_comp_def(ctx: Context<InitPlaceOrderCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the match_orders computation definition
+    pub fn init_match_orders_comp_def(ctx: Context<InitMatchOrdersCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the settle_order computation definition
+    pub fn init_settle_order_comp_def(ctx: Context<InitSettleOrderCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the batch auction computation definition
+    pub fn init_batch_auction_comp_def(ctx: Context<InitBatchAuctionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the init_auction computation definition
+    pub fn init_init_auction_comp_def(ctx: Context<InitInitAuctionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the submit_bid computation definition
+    pub fn init_submit_bid_comp_def(ctx: Context<InitSubmitBidCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the resolve_auction computation definition
+    pub fn init_resolve_auction_comp_def(ctx: Context<InitResolveAuctionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the settle_bid computation definition
+    pub fn init_settle_bid_comp_def(ctx: Context<InitSettleBidCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the settle_auction computation definition
+    pub fn init_settle_auction_comp_def(ctx: Context<InitSettleAuctionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the disclose_balance computation definition
+    pub fn init_disclose_balance_comp_def(ctx: Context<InitDiscloseBalanceCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
     // =========================================================================
-    // PRIVATE TRANSFER
+    // ENCRYPTED BALANCES
     // =========================================================================
 
-    /// Queue a private transfer computation
+    /// Create the program vault holding deposited tokens for a mint
+    pub fn init_vault(_ctx: Context<InitVault>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Create the fee treasury for a mint (admin only)
+    ///
+    /// Transfers of a mint without a treasury charge no fee. With one, each
+    /// fee-charging transfer writes its fee to a `FeeRecord`, which the
+    /// authority sweeps into its own encrypted balance with `sweep_fee`.
+    ///
+    /// # Arguments
+    /// * `fee_bps` - Fee in basis points of each private transfer amount
+    /// * `authority` - Wallet allowed to sweep the fees into its encrypted balance
+    pub fn init_treasury(
+        ctx: Context<InitTreasury>,
+        fee_bps: u16,
+        authority: Pubkey,
+    ) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.mint = ctx.accounts.mint.key();
+        treasury.authority = authority;
+        treasury.fee_bps = fee_bps;
+        treasury.bump = ctx.bumps.treasury;
+
+        Ok(())
+    }
+
+    /// Change a treasury's fee rate (admin only)
+    ///
+    /// Applies to transfers queued afterwards.
+    pub fn set_treasury_fee(ctx: Context<SetTreasuryFee>, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+        ctx.accounts.treasury.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    /// Create the payer's encrypted balance account for a mint
+    ///
+    /// Queues the `create_balance` circuit, which writes MXE-encrypted zeros
+    /// into the spendable and incoming balances. The account cannot be used
+    /// until the callback lands.
     ///
     /// # Arguments
     /// * `computation_offset` - Unique offset for this computation
-    /// * `encrypted_sender_balance` - Encrypted u64 (32 bytes)
-    /// * `encrypted_amount` - Encrypted u64 (32 bytes)
-    /// * `encrypted_min_balance` - Encrypted u64 (32 bytes)
-    /// * `pubkey` - X25519 public key for result encryption
-    /// * `nonce` - Encryption nonce
-    pub fn private_transfer(
-        ctx: Context<PrivateTransfer>,
+    /// * `encryption_pubkey` - X25519 public key that incoming transfer receipts are encrypted to
+    pub fn create_balance(
+        ctx: Context<CreateBalance>,
         computation_offset: u64,
-        encrypted_sender_balance: [u8; 32],
-        encrypted_amount: [u8; 32],
-        encrypted_min_balance: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
+        encryption_pubkey: [u8; 32],
     ) -> Result<()> {
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::CreateBalance,
+            computation_offset,
+            &[ctx.accounts.balance_account.key()],
+            ctx.bumps.computation_request,
+        )?;
 
-        // Build args matching PrivateTransferInput struct order
-        let args = ArgBuilder::new()
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_sender_balance)
-            .encrypted_u64(encrypted_amount)
-            .encrypted_u64(encrypted_min_balance)
-            .build();
+        let balance_account = &mut ctx.accounts.balance_account;
+        balance_account.owner = ctx.accounts.payer.key();
+        balance_account.mint = ctx.accounts.mint.key();
+        balance_account.encryption_pubkey = encryption_pubkey;
+        balance_account.bump = ctx.bumps.balance_account;
+        balance_account.lock(computation_offset)?;
+
+        let args = ArgBuilder::new().build();
 
         queue_computation(
             ctx.accounts,
             computation_offset,
             args,
             None,
-            vec![PrivateTransferCallback::callback_ix(
+            vec![CreateBalanceCallback::callback_ix(
                 computation_offset,
                 &ctx.accounts.mxe_account,
-                &[],
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
             )?],
             1,
             0,
@@ -95,53 +456,94 @@ pub mod sip_arcium_transfer {
         Ok(())
     }
 
-    /// Callback for private transfer result
-    #[arcium_callback(encrypted_ix = "private_transfer")]
-    pub fn private_transfer_callback(
-        ctx: Context<PrivateTransferCallback>,
-        output: SignedComputationOutputs<PrivateTransferOutput>,
+    #[arcium_callback(encrypted_ix = "create_balance")]
+    pub fn create_balance_callback(
+        ctx: Context<CreateBalanceCallback>,
+        output: SignedComputationOutputs<CreateBalanceOutput>,
     ) -> Result<()> {
+        // The balance never received its initial ciphertext; close it so the
+        // owner can retry `create_balance`
+        if matches!(output, SignedComputationOutputs::Failure) {
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .balance_account
+                .close(ctx.accounts.owner.to_account_info())?;
+            return Ok(());
+        }
+
         let verified = output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         );
 
         match verified {
-            Ok(PrivateTransferOutput { field_0 }) => {
-                // field_0.ciphertexts[0] = is_valid (bool)
-                // field_0.ciphertexts[1] = new_sender_balance (u64)
-                emit!(PrivateTransferEvent {
-                    is_valid: field_0.ciphertexts[0],
-                    new_sender_balance: field_0.ciphertexts[1],
-                    nonce: field_0.nonce.to_le_bytes(),
+            Ok(CreateBalanceOutput { field_0 }) => {
+                // field_0.field_0 = spendable balance (Enc<Mxe, u64>)
+                // field_0.field_1 = incoming balance (Enc<Mxe, u64>)
+                let balance = field_0.field_0;
+                let incoming = field_0.field_1;
+
+                let balance_account = &mut ctx.accounts.balance_account;
+                balance_account.set_balance(balance.ciphertexts[0], balance.nonce);
+                balance_account.set_incoming(incoming.ciphertexts[0], incoming.nonce);
+                balance_account.is_initialized = true;
+
+                emit!(BalanceCreatedEvent {
+                    owner: balance_account.owner,
+                    mint: balance_account.mint,
                 });
             }
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
         }
 
+        ctx.accounts.computation_request.complete()?;
+
         Ok(())
     }
 
     // =========================================================================
-    // BALANCE CHECK
+    // DEPOSIT / WITHDRAW
     // =========================================================================
 
-    /// Queue a balance check computation
-    pub fn check_balance(
-        ctx: Context<CheckBalance>,
-        computation_offset: u64,
-        encrypted_balance: [u8; 32],
-        encrypted_minimum: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
+    /// Deposit public tokens into the vault and credit the encrypted balance
+    ///
+    /// Tokens move immediately; the MXE adds `amount` to the balance in the
+    /// callback.
+    pub fn deposit(ctx: Context<Deposit>, computation_offset: u64, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::Deposit,
+            computation_offset,
+            &[ctx.accounts.balance_account.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.computation_request.escrow(ctx.accounts.mint.key(), amount);
+        ctx.accounts.balance_account.lock(computation_offset)?;
+
+        token::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
 
         let args = ArgBuilder::new()
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_balance)
-            .encrypted_u64(encrypted_minimum)
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(amount)
             .build();
 
         queue_computation(
@@ -149,65 +551,123 @@ pub mod sip_arcium_transfer {
             computation_offset,
             args,
             None,
-            vec![CheckBalanceCallback::callback_ix(
+            vec![DepositCallback::callback_ix(
                 computation_offset,
                 &ctx.accounts.mxe_account,
-                &[],
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.mint.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer_token_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.token_program.key(),
+                        is_writable: false,
+                    },
+                ],
             )?],
             1,
             0,
         )?;
 
+        emit!(DepositEvent {
+            owner: ctx.accounts.payer.key(),
+            mint: ctx.accounts.mint.key(),
+            amount,
+        });
+
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "check_balance")]
-    pub fn check_balance_callback(
-        ctx: Context<CheckBalanceCallback>,
-        output: SignedComputationOutputs<CheckBalanceOutput>,
+    #[arcium_callback(encrypted_ix = "deposit")]
+    pub fn deposit_callback(
+        ctx: Context<DepositCallback>,
+        output: SignedComputationOutputs<DepositOutput>,
     ) -> Result<()> {
+        // The tokens already moved into the vault; return them
+        if matches!(output, SignedComputationOutputs::Failure) {
+            let refund = ctx.accounts.computation_request.escrowed_amount;
+            let mint_key = ctx.accounts.mint.key();
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[VAULT_SEED, mint_key.as_ref(), &[ctx.bumps.vault]]];
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.vault.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.payer_token_account.to_account_info(),
+                        authority: ctx.accounts.vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                refund,
+                ctx.accounts.mint.decimals,
+            )?;
+
+            ctx.accounts.balance_account.unlock();
+
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
         let verified = output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         );
 
         match verified {
-            Ok(CheckBalanceOutput { field_0 }) => {
-                emit!(BalanceCheckEvent {
-                    meets_minimum: field_0.ciphertexts[0],
-                    nonce: field_0.nonce.to_le_bytes(),
-                });
+            Ok(DepositOutput { field_0 }) => {
+                let balance_account = &mut ctx.accounts.balance_account;
+                balance_account.set_balance(field_0.ciphertexts[0], field_0.nonce);
             }
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
         }
 
+        ctx.accounts.computation_request.complete()?;
+
         Ok(())
     }
 
-    // =========================================================================
-    // VALIDATE SWAP
-    // =========================================================================
-
-    /// Queue a confidential swap validation
-    pub fn validate_swap(
-        ctx: Context<ValidateSwap>,
-        computation_offset: u64,
-        encrypted_input_balance: [u8; 32],
-        encrypted_input_amount: [u8; 32],
-        encrypted_min_output: [u8; 32],
-        encrypted_actual_output: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
+    /// Withdraw tokens from the vault against the encrypted balance
+    ///
+    /// The MXE checks the balance; the callback releases `amount` to
+    /// `destination` only if the balance covered it.
+    pub fn withdraw(ctx: Context<Withdraw>, computation_offset: u64, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::Withdraw,
+            computation_offset,
+            &[ctx.accounts.balance_account.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.balance_account.lock(computation_offset)?;
 
         let args = ArgBuilder::new()
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_input_balance)
-            .encrypted_u64(encrypted_input_amount)
-            .encrypted_u64(encrypted_min_output)
-            .encrypted_u64(encrypted_actual_output)
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(amount)
             .build();
 
         queue_computation(
@@ -215,10 +675,35 @@ pub mod sip_arcium_transfer {
             computation_offset,
             args,
             None,
-            vec![ValidateSwapCallback::callback_ix(
+            vec![WithdrawCallback::callback_ix(
                 computation_offset,
                 &ctx.accounts.mxe_account,
-                &[],
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.mint.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.destination.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.token_program.key(),
+                        is_writable: false,
+                    },
+                ],
             )?],
             1,
             0,
@@ -227,50 +712,7311 @@ pub mod sip_arcium_transfer {
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "validate_swap")]
-    pub fn validate_swap_callback(
-        ctx: Context<ValidateSwapCallback>,
-        output: SignedComputationOutputs<ValidateSwapOutput>,
+    #[arcium_callback(encrypted_ix = "withdraw")]
+    pub fn withdraw_callback(
+        ctx: Context<WithdrawCallback>,
+        output: SignedComputationOutputs<WithdrawOutput>,
     ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.balance_account.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
         let verified = output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         );
 
         match verified {
-            Ok(ValidateSwapOutput { field_0 }) => {
-                // field_0.ciphertexts[0] = is_valid (bool)
-                // field_0.ciphertexts[1] = new_input_balance (u64)
-                // field_0.ciphertexts[2] = slippage_ok (bool)
-                emit!(SwapValidationEvent {
-                    is_valid: field_0.ciphertexts[0],
-                    new_input_balance: field_0.ciphertexts[1],
-                    slippage_ok: field_0.ciphertexts[2],
-                    nonce: field_0.nonce.to_le_bytes(),
+            Ok(WithdrawOutput { field_0 }) => {
+                // field_0.field_0 = updated balance (Enc<Mxe, u64>)
+                // field_0.field_1 = amount released (revealed u64, zero if rejected)
+                let new_balance = field_0.field_0;
+                let released = field_0.field_1;
+
+                let balance_account = &mut ctx.accounts.balance_account;
+                balance_account.set_balance(new_balance.ciphertexts[0], new_balance.nonce);
+
+                if released > 0 {
+                    let mint_key = ctx.accounts.mint.key();
+                    let signer_seeds: &[&[&[u8]]] =
+                        &[&[VAULT_SEED, mint_key.as_ref(), &[ctx.bumps.vault]]];
+                    token::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.vault.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                to: ctx.accounts.destination.to_account_info(),
+                                authority: ctx.accounts.vault.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        released,
+                        ctx.accounts.mint.decimals,
+                    )?;
+                }
+
+                emit!(WithdrawEvent {
+                    owner: balance_account.owner,
+                    mint: balance_account.mint,
+                    destination: ctx.accounts.destination.key(),
+                    amount: released,
                 });
             }
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
         }
 
+        ctx.accounts.computation_request.complete()?;
+
         Ok(())
     }
+
+    // =========================================================================
+    // PRIVATE TRANSFER
+    // =========================================================================
+
+    /// Queue a private transfer computation
+    ///
+    /// The sender balance is read by the MXE straight from the sender's
+    /// `EncryptedBalanceAccount`, so it cannot be self-reported. The recipient
+    /// is named inside the encrypted input; the MXE only credits
+    /// `recipient_balance` if its owner matches. The credit goes to the
+    /// recipient's incoming balance, which only `claim_incoming` moves into
+    /// the spendable one, so a transfer never locks the recipient's balance
+    /// or changes its `sequence`. If the mint has a `Treasury` with a non-zero
+    /// `fee_bps`, the MXE charges the fee on top of the amount and writes it
+    /// to `fee_record`, which must then be passed; otherwise it must be
+    /// omitted. No account is shared between transfers, so concurrent
+    /// transfers never wait on each other. Both sides are also encrypted to
+    /// the balances' viewing keys.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amount` - Encrypted u64 (32 bytes)
+    /// * `encrypted_min_balance` - Encrypted u64 (32 bytes)
+    /// * `encrypted_recipient` - Recipient address as two encrypted u128 limbs (low, high)
+    /// * `encrypted_sequence` - Encrypted u64, sender balance `sequence` the input targets
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    #[allow(clippy::too_many_arguments)]
+    pub fn private_transfer(
+        ctx: Context<PrivateTransfer>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        encrypted_min_balance: [u8; 32],
+        encrypted_recipient: [[u8; 32]; 2],
+        encrypted_sequence: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let fee_bps = treasury_fee_bps(&ctx.accounts.treasury)?;
+        require!(
+            ctx.accounts.fee_record.is_some() == (fee_bps > 0),
+            ErrorCode::FeeRecordMismatch
+        );
+        let fee_record_key = ctx.accounts.fee_record.as_ref().map(|record| record.key());
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let mut locked_accounts = vec![
+            ctx.accounts.sender_balance.key(),
+            ctx.accounts.recipient_balance.key(),
+        ];
+        locked_accounts.extend(fee_record_key);
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::PrivateTransfer,
+            computation_offset,
+            &locked_accounts,
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.sender_balance.lock(computation_offset)?;
+        ctx.accounts.recipient_balance.lock_incoming(computation_offset)?;
+        if let (Some(fee_record), Some(bump)) =
+            (&mut ctx.accounts.fee_record, ctx.bumps.fee_record)
+        {
+            fee_record.open(
+                ctx.accounts.sender_balance.mint,
+                ctx.accounts.payer.key(),
+                computation_offset,
+                bump,
+            );
+        }
+
+        let recipient_balance = &ctx.accounts.recipient_balance;
+        let (recipient_owner_lo, recipient_owner_hi) = pubkey_to_limbs(&recipient_balance.owner);
+
+        // Build args matching PrivateTransferInput struct order,
+        // followed by the sender's balance, the recipient's incoming
+        // balance, the viewing keys, the recipient's identity and the fee rate
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount)
+            .encrypted_u64(encrypted_min_balance)
+            .encrypted_u128(encrypted_recipient[0])
+            .encrypted_u128(encrypted_recipient[1])
+            .encrypted_u64(encrypted_sequence)
+            .plaintext_u128(ctx.accounts.sender_balance.nonce)
+            .account(
+                ctx.accounts.sender_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(ctx.accounts.sender_balance.sequence)
+            .x25519_pubkey(ctx.accounts.sender_balance.view_key())
+            .plaintext_u128(recipient_balance.incoming_nonce)
+            .account(
+                recipient_balance.key(),
+                INCOMING_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(recipient_owner_lo)
+            .plaintext_u128(recipient_owner_hi)
+            .x25519_pubkey(recipient_balance.encryption_pubkey)
+            .x25519_pubkey(recipient_balance.view_key())
+            .plaintext_u16(fee_bps)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![PrivateTransferCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.sender_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.recipient_balance.key(),
+                        is_writable: true,
+                    },
+                    fee_record_callback_account(fee_record_key),
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for private transfer result
+    ///
+    /// Persists every side of the ledger update. The circuit returns the
+    /// sender balance and recipient incoming balance unchanged, and a zero
+    /// fee, when the transfer is rejected, so they are always written
+    /// together.
+    #[arcium_callback(encrypted_ix = "private_transfer")]
+    pub fn private_transfer_callback(
+        ctx: Context<PrivateTransferCallback>,
+        output: SignedComputationOutputs<PrivateTransferOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.sender_balance.unlock();
+            ctx.accounts.recipient_balance.unlock_incoming();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            // No fee was charged; return the record's rent to the sender
+            if let Some(fee_record) = &ctx.accounts.fee_record {
+                fee_record.close(ctx.accounts.sender.to_account_info())?;
+            }
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(PrivateTransferOutput { field_0 }) => {
+                // field_0.field_0 = result for the sender (Enc<Shared, _>)
+                // field_0.field_1 = receipt for the recipient (Enc<Shared, _>)
+                // field_0.field_2 = updated sender balance (Enc<Mxe, u64>)
+                // field_0.field_3 = updated recipient incoming balance (Enc<Mxe, u64>)
+                // field_0.field_4 = fee charged (Enc<Mxe, u64>)
+                // field_0.field_5 = debit for the sender's viewing key (Enc<Shared, _>)
+                // field_0.field_6 = credit for the recipient's viewing key (Enc<Shared, _>)
+                let result = field_0.field_0;
+                let receipt = field_0.field_1;
+                let new_sender_balance = field_0.field_2;
+                let new_incoming = field_0.field_3;
+                let fee = field_0.field_4;
+                let debit_view = field_0.field_5;
+                let credit_view = field_0.field_6;
+
+                let sender_balance = &mut ctx.accounts.sender_balance;
+                sender_balance.set_balance(
+                    new_sender_balance.ciphertexts[0],
+                    new_sender_balance.nonce,
+                );
+
+                let recipient_balance = &mut ctx.accounts.recipient_balance;
+                recipient_balance.set_incoming(new_incoming.ciphertexts[0], new_incoming.nonce);
+
+                if let Some(fee_record) = &mut ctx.accounts.fee_record {
+                    fee_record.set_fee(fee.ciphertexts[0], fee.nonce);
+                }
+
+                // result.ciphertexts[0] = is_valid (bool)
+                // result.ciphertexts[1] = new_sender_balance (u64)
+                // result.ciphertexts[2] = error_code (u8)
+                // result.ciphertexts[3] = fee (u64)
+                emit!(PrivateTransferEvent {
+                    is_valid: result.ciphertexts[0],
+                    new_sender_balance: result.ciphertexts[1],
+                    error_code: result.ciphertexts[2],
+                    fee: result.ciphertexts[3],
+                    nonce: result.nonce.to_le_bytes(),
+                });
+
+                // receipt.ciphertexts[0] = amount (u64)
+                // receipt.ciphertexts[1] = new_incoming (u64)
+                emit!(TransferReceivedEvent {
+                    recipient: recipient_balance.owner,
+                    amount: receipt.ciphertexts[0],
+                    new_incoming: receipt.ciphertexts[1],
+                    nonce: receipt.nonce.to_le_bytes(),
+                });
+
+                let sender_balance = &ctx.accounts.sender_balance;
+                let recipient_balance = &ctx.accounts.recipient_balance;
+
+                // debit_view.ciphertexts[0] = amount (u64)
+                // debit_view.ciphertexts[1] = fee (u64)
+                // debit_view.ciphertexts[2] = new_sender_balance (u64)
+                if sender_balance.viewing_key.is_some() {
+                    emit!(TransferSentViewEvent {
+                        balance_account: sender_balance.key(),
+                        recipient: recipient_balance.owner,
+                        sequence: sender_balance.sequence,
+                        amount: debit_view.ciphertexts[0],
+                        fee: debit_view.ciphertexts[1],
+                        new_sender_balance: debit_view.ciphertexts[2],
+                        nonce: debit_view.nonce.to_le_bytes(),
+                    });
+                }
+
+                // credit_view.ciphertexts[0] = amount (u64)
+                // credit_view.ciphertexts[1] = new_incoming (u64)
+                if recipient_balance.viewing_key.is_some() {
+                    emit!(TransferReceivedViewEvent {
+                        balance_account: recipient_balance.key(),
+                        sender: sender_balance.owner,
+                        amount: credit_view.ciphertexts[0],
+                        new_incoming: credit_view.ciphertexts[1],
+                        nonce: credit_view.nonce.to_le_bytes(),
+                    });
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // BATCH TRANSFER
+    // =========================================================================
+
+    /// Queue a batch transfer paying up to `BATCH_SIZE` recipients
+    ///
+    /// Every slot needs a distinct, initialized balance account of the
+    /// sender's mint. Slots with nothing to pay take any other balance and an
+    /// encrypted zero amount, which the MXE leaves unchanged and which hides
+    /// how many recipients were actually paid. The whole batch is applied or
+    /// rejected together. As with `private_transfer`, each slot credits the
+    /// recipient's incoming balance, and `fee_record` is passed exactly when
+    /// the mint's treasury charges a fee.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amounts` - Encrypted u64 amount per slot (zero for unused slots)
+    /// * `encrypted_recipients` - Recipient address per slot as two encrypted u128 limbs
+    /// * `encrypted_min_balance` - Encrypted u64 (32 bytes)
+    /// * `encrypted_sequence` - Encrypted u64, sender balance `sequence` the input targets
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    #[allow(clippy::too_many_arguments)]
+    pub fn batch_transfer(
+        ctx: Context<BatchTransfer>,
+        computation_offset: u64,
+        encrypted_amounts: [[u8; 32]; BATCH_SIZE],
+        encrypted_recipients: [[[u8; 32]; 2]; BATCH_SIZE],
+        encrypted_min_balance: [u8; 32],
+        encrypted_sequence: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let recipient_keys = ctx.accounts.recipient_keys();
+        for (i, key) in recipient_keys.iter().enumerate() {
+            require!(
+                !recipient_keys[..i].contains(key),
+                ErrorCode::DuplicateRecipient
+            );
+        }
+
+        let fee_bps = treasury_fee_bps(&ctx.accounts.treasury)?;
+        require!(
+            ctx.accounts.fee_record.is_some() == (fee_bps > 0),
+            ErrorCode::FeeRecordMismatch
+        );
+        let fee_record_key = ctx.accounts.fee_record.as_ref().map(|record| record.key());
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let mut locked_accounts = vec![ctx.accounts.sender_balance.key()];
+        locked_accounts.extend(recipient_keys);
+        locked_accounts.extend(fee_record_key);
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::BatchTransfer,
+            computation_offset,
+            &locked_accounts,
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.sender_balance.lock(computation_offset)?;
+        if let (Some(fee_record), Some(bump)) =
+            (&mut ctx.accounts.fee_record, ctx.bumps.fee_record)
+        {
+            fee_record.open(
+                ctx.accounts.sender_balance.mint,
+                ctx.accounts.payer.key(),
+                computation_offset,
+                bump,
+            );
+        }
+        for recipient_balance in [
+            &mut ctx.accounts.recipient_balance_0,
+            &mut ctx.accounts.recipient_balance_1,
+            &mut ctx.accounts.recipient_balance_2,
+            &mut ctx.accounts.recipient_balance_3,
+        ] {
+            recipient_balance.lock_incoming(computation_offset)?;
+        }
+
+        // Build args matching BatchTransferInput struct order (each array in
+        // slot order), followed by the sender's balance, the recipients'
+        // incoming balances and identities, the viewing keys and the fee rate
+        let mut args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce);
+        for amount in encrypted_amounts {
+            args = args.encrypted_u64(amount);
+        }
+        for recipient in encrypted_recipients {
+            args = args.encrypted_u128(recipient[0]);
+        }
+        for recipient in encrypted_recipients {
+            args = args.encrypted_u128(recipient[1]);
+        }
+        args = args
+            .encrypted_u64(encrypted_min_balance)
+            .encrypted_u64(encrypted_sequence)
+            .plaintext_u128(ctx.accounts.sender_balance.nonce)
+            .account(
+                ctx.accounts.sender_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(ctx.accounts.sender_balance.sequence)
+            .x25519_pubkey(ctx.accounts.sender_balance.view_key());
+
+        let recipients = ctx.accounts.recipient_balances();
+        for (recipient_balance, key) in recipients.iter().zip(recipient_keys) {
+            args = args.plaintext_u128(recipient_balance.incoming_nonce).account(
+                key,
+                INCOMING_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            );
+        }
+        let owner_limbs =
+            recipients.map(|recipient_balance| pubkey_to_limbs(&recipient_balance.owner));
+        for (owner_lo, _) in owner_limbs {
+            args = args.plaintext_u128(owner_lo);
+        }
+        for (_, owner_hi) in owner_limbs {
+            args = args.plaintext_u128(owner_hi);
+        }
+        for recipient_balance in recipients {
+            args = args.x25519_pubkey(recipient_balance.view_key());
+        }
+        let args = args.plaintext_u16(fee_bps).build();
+
+        let mut callback_accounts = vec![
+            CallbackAccount {
+                pubkey: ctx.accounts.computation_request.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.sender_balance.key(),
+                is_writable: true,
+            },
+        ];
+        callback_accounts.extend(recipient_keys.map(|pubkey| CallbackAccount {
+            pubkey,
+            is_writable: true,
+        }));
+        callback_accounts.push(fee_record_callback_account(fee_record_key));
+        callback_accounts.push(CallbackAccount {
+            pubkey: ctx.accounts.payer.key(),
+            is_writable: true,
+        });
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![BatchTransferCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for batch transfer result
+    ///
+    /// Writes the sender balance, every recipient's incoming balance and the
+    /// fee record. The circuit returns the balances unchanged and a zero fee
+    /// when the batch is rejected. Views are emitted for each side that has a
+    /// viewing key registered.
+    #[arcium_callback(encrypted_ix = "batch_transfer")]
+    pub fn batch_transfer_callback(
+        ctx: Context<BatchTransferCallback>,
+        output: SignedComputationOutputs<BatchTransferOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.sender_balance.unlock();
+            for recipient_balance in ctx.accounts.recipient_balances_mut() {
+                recipient_balance.unlock_incoming();
+            }
+            record_abort(&mut ctx.accounts.computation_request)?;
+            // No fee was charged; return the record's rent to the sender
+            if let Some(fee_record) = &ctx.accounts.fee_record {
+                fee_record.close(ctx.accounts.sender.to_account_info())?;
+            }
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(BatchTransferOutput { field_0 }) => {
+                // field_0.field_0 = result for the sender (Enc<Shared, _>)
+                // field_0.field_1 = updated sender balance (Enc<Mxe, u64>)
+                // field_0.field_2 = updated recipient incoming balances, in slot order
+                // field_0.field_3 = fee charged (Enc<Mxe, u64>)
+                // field_0.field_4 = debit for the sender's viewing key (Enc<Shared, _>)
+                // field_0.field_5 = credit per slot for the recipients' viewing keys
+                let result = field_0.field_0;
+                let new_sender_balance = field_0.field_1;
+                let new_incoming = field_0.field_2;
+                let fee = field_0.field_3;
+                let debit_view = field_0.field_4;
+                let credit_views = field_0.field_5;
+
+                ctx.accounts
+                    .sender_balance
+                    .set_balance(new_sender_balance.ciphertexts[0], new_sender_balance.nonce);
+                if let Some(fee_record) = &mut ctx.accounts.fee_record {
+                    fee_record.set_fee(fee.ciphertexts[0], fee.nonce);
+                }
+                for (recipient_balance, incoming) in ctx
+                    .accounts
+                    .recipient_balances_mut()
+                    .into_iter()
+                    .zip(new_incoming)
+                {
+                    recipient_balance.set_incoming(incoming.ciphertexts[0], incoming.nonce);
+                }
+
+                // result.ciphertexts[0] = is_valid (bool)
+                // result.ciphertexts[1] = new_sender_balance (u64)
+                // result.ciphertexts[2] = error_code (u8)
+                // result.ciphertexts[3] = fee (u64)
+                let recipients = ctx
+                    .accounts
+                    .recipient_balances()
+                    .map(|balance| balance.owner);
+                emit!(BatchTransferEvent {
+                    recipients,
+                    is_valid: result.ciphertexts[0],
+                    new_sender_balance: result.ciphertexts[1],
+                    error_code: result.ciphertexts[2],
+                    fee: result.ciphertexts[3],
+                    nonce: result.nonce.to_le_bytes(),
+                });
+
+                // debit_view.ciphertexts[0..BATCH_SIZE] = amount per slot (u64)
+                // debit_view.ciphertexts[BATCH_SIZE] = fee (u64)
+                // debit_view.ciphertexts[BATCH_SIZE + 1] = new_sender_balance (u64)
+                let sender_balance = &ctx.accounts.sender_balance;
+                if sender_balance.viewing_key.is_some() {
+                    emit!(BatchTransferSentViewEvent {
+                        balance_account: sender_balance.key(),
+                        recipients,
+                        sequence: sender_balance.sequence,
+                        amounts: std::array::from_fn(|i| debit_view.ciphertexts[i]),
+                        fee: debit_view.ciphertexts[BATCH_SIZE],
+                        new_sender_balance: debit_view.ciphertexts[BATCH_SIZE + 1],
+                        nonce: debit_view.nonce.to_le_bytes(),
+                    });
+                }
+
+                // credit_view.ciphertexts[0] = amount (u64)
+                // credit_view.ciphertexts[1] = new_incoming (u64)
+                let recipient_keys = ctx.accounts.recipient_keys();
+                for ((recipient_balance, key), credit_view) in ctx
+                    .accounts
+                    .recipient_balances()
+                    .into_iter()
+                    .zip(recipient_keys)
+                    .zip(credit_views)
+                {
+                    if recipient_balance.viewing_key.is_some() {
+                        emit!(TransferReceivedViewEvent {
+                            balance_account: key,
+                            sender: sender_balance.owner,
+                            amount: credit_view.ciphertexts[0],
+                            new_incoming: credit_view.ciphertexts[1],
+                            nonce: credit_view.nonce.to_le_bytes(),
+                        });
+                    }
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // FEE SWEEPS
+    // =========================================================================
+
+    /// Credit a transfer's `FeeRecord` to the treasury authority's balance
+    ///
+    /// Only the mint's `Treasury::authority` may sweep, into its own
+    /// `EncryptedBalanceAccount` for the mint. Once the MXE has added the fee
+    /// the record is closed and its rent returned to the sender who paid it.
+    /// Stays available while the program is paused, like `withdraw`.
+    pub fn sweep_fee(ctx: Context<SweepFee>, computation_offset: u64) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::SweepFee,
+            computation_offset,
+            &[ctx.accounts.balance_account.key(), ctx.accounts.fee_record.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.balance_account.lock(computation_offset)?;
+        ctx.accounts.fee_record.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.fee_record.nonce)
+            .account(
+                ctx.accounts.fee_record.key(),
+                FEE_RECORD_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SweepFeeCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_record.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_record.payer,
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for a fee sweep
+    ///
+    /// Closes the fee record once its fee is in the balance. A fee that would
+    /// overflow the balance is left in the record for a later sweep.
+    #[arcium_callback(encrypted_ix = "sweep_fee")]
+    pub fn sweep_fee_callback(
+        ctx: Context<SweepFeeCallback>,
+        output: SignedComputationOutputs<SweepFeeOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.balance_account.unlock();
+            ctx.accounts.fee_record.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SweepFeeOutput { field_0 }) => {
+                // field_0.field_0 = updated balance (Enc<Mxe, u64>)
+                // field_0.field_1 = whether the fee was credited (revealed bool)
+                let new_balance = field_0.field_0;
+                let swept = field_0.field_1;
+
+                let balance_account = &mut ctx.accounts.balance_account;
+                balance_account.set_balance(new_balance.ciphertexts[0], new_balance.nonce);
+
+                emit!(FeeSweptEvent {
+                    authority: balance_account.owner,
+                    mint: balance_account.mint,
+                    fee_record: ctx.accounts.fee_record.key(),
+                    sequence: balance_account.sequence,
+                    swept,
+                });
+
+                if swept {
+                    ctx.accounts
+                        .fee_record
+                        .close(ctx.accounts.fee_payer.to_account_info())?;
+                } else {
+                    ctx.accounts.fee_record.unlock();
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // INCOMING TRANSFERS
+    // =========================================================================
+
+    /// Move everything credited to the payer's incoming balance into the
+    /// spendable balance
+    ///
+    /// Stays available while the program is paused, like `withdraw`. The
+    /// result is encrypted to the balance's `encryption_pubkey`, and to its
+    /// viewing key if one is registered.
+    pub fn claim_incoming(ctx: Context<ClaimIncoming>, computation_offset: u64) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::ClaimIncoming,
+            computation_offset,
+            &[ctx.accounts.balance_account.key()],
+            ctx.bumps.computation_request,
+        )?;
+        let balance_account = &mut ctx.accounts.balance_account;
+        balance_account.lock(computation_offset)?;
+        balance_account.lock_incoming(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(balance_account.nonce)
+            .account(
+                balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(balance_account.incoming_nonce)
+            .account(
+                balance_account.key(),
+                INCOMING_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .x25519_pubkey(balance_account.encryption_pubkey)
+            .x25519_pubkey(balance_account.view_key())
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ClaimIncomingCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "claim_incoming")]
+    pub fn claim_incoming_callback(
+        ctx: Context<ClaimIncomingCallback>,
+        output: SignedComputationOutputs<ClaimIncomingOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.balance_account.unlock();
+            ctx.accounts.balance_account.unlock_incoming();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(ClaimIncomingOutput { field_0 }) => {
+                // field_0.field_0 = updated spendable balance (Enc<Mxe, u64>)
+                // field_0.field_1 = updated incoming balance (Enc<Mxe, u64>)
+                // field_0.field_2 = claim for the owner (Enc<Shared, _>)
+                // field_0.field_3 = claim for the viewing key (Enc<Shared, _>)
+                let new_balance = field_0.field_0;
+                let new_incoming = field_0.field_1;
+                let claim = field_0.field_2;
+                let claim_view = field_0.field_3;
+
+                let balance_account = &mut ctx.accounts.balance_account;
+                balance_account.set_balance(new_balance.ciphertexts[0], new_balance.nonce);
+                balance_account.set_incoming(new_incoming.ciphertexts[0], new_incoming.nonce);
+
+                // claim.ciphertexts[0] = claimed (u64)
+                // claim.ciphertexts[1] = new_balance (u64)
+                emit!(IncomingClaimedEvent {
+                    owner: balance_account.owner,
+                    mint: balance_account.mint,
+                    sequence: balance_account.sequence,
+                    claimed: claim.ciphertexts[0],
+                    new_balance: claim.ciphertexts[1],
+                    nonce: claim.nonce.to_le_bytes(),
+                });
+
+                if balance_account.viewing_key.is_some() {
+                    emit!(IncomingClaimedViewEvent {
+                        balance_account: balance_account.key(),
+                        sequence: balance_account.sequence,
+                        claimed: claim_view.ciphertexts[0],
+                        new_balance: claim_view.ciphertexts[1],
+                        nonce: claim_view.nonce.to_le_bytes(),
+                    });
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // BALANCE CHECK
+    // =========================================================================
+
+    /// Queue a balance check computation against the payer's encrypted balance
+    pub fn check_balance(
+        ctx: Context<CheckBalance>,
+        computation_offset: u64,
+        encrypted_minimum: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::CheckBalance,
+            computation_offset,
+            &[],
+            ctx.bumps.computation_request,
+        )?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_minimum)
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CheckBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.computation_request.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "check_balance")]
+    pub fn check_balance_callback(
+        ctx: Context<CheckBalanceCallback>,
+        output: SignedComputationOutputs<CheckBalanceOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(CheckBalanceOutput { field_0 }) => {
+                emit!(BalanceCheckEvent {
+                    meets_minimum: field_0.ciphertexts[0],
+                    nonce: field_0.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // VALIDATE SWAP
+    // =========================================================================
+
+    /// Queue a confidential swap validation against the payer's encrypted balances
+    ///
+    /// The MXE reports the input balance debited by `input_amount` and the
+    /// output balance credited by `actual_output`, both only if the swap is
+    /// valid. The program does not write them: the output amount is the
+    /// user's own quote, so persisting the credit would mint unbacked output
+    /// tokens. Settlement moves the real tokens and balances.
+    pub fn validate_swap(
+        ctx: Context<ValidateSwap>,
+        computation_offset: u64,
+        encrypted_input_amount: [u8; 32],
+        encrypted_min_output: [u8; 32],
+        encrypted_actual_output: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::ValidateSwap,
+            computation_offset,
+            &[],
+            ctx.bumps.computation_request,
+        )?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_input_amount)
+            .encrypted_u64(encrypted_min_output)
+            .encrypted_u64(encrypted_actual_output)
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.output_balance_account.nonce)
+            .account(
+                ctx.accounts.output_balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ValidateSwapCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.computation_request.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "validate_swap")]
+    pub fn validate_swap_callback(
+        ctx: Context<ValidateSwapCallback>,
+        output: SignedComputationOutputs<ValidateSwapOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(ValidateSwapOutput { field_0 }) => {
+                // field_0.ciphertexts[0] = is_valid (bool)
+                // field_0.ciphertexts[1] = new_input_balance (u64)
+                // field_0.ciphertexts[2] = slippage_ok (bool)
+                // field_0.ciphertexts[3] = new_output_balance (u64)
+                emit!(SwapValidationEvent {
+                    is_valid: field_0.ciphertexts[0],
+                    new_input_balance: field_0.ciphertexts[1],
+                    slippage_ok: field_0.ciphertexts[2],
+                    new_output_balance: field_0.ciphertexts[3],
+                    nonce: field_0.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // ORACLE SWAP
+    // =========================================================================
+
+    /// Create the price feed for swapping `input_mint` into `output_mint` (admin only)
+    ///
+    /// `authority` is the only signer allowed to publish prices, typically a
+    /// relayer mirroring an external oracle. The feed is stale until its
+    /// first update.
+    pub fn init_price_feed(ctx: Context<InitPriceFeed>, authority: Pubkey) -> Result<()> {
+        let price_feed = &mut ctx.accounts.price_feed;
+        price_feed.input_mint = ctx.accounts.input_mint.key();
+        price_feed.output_mint = ctx.accounts.output_mint.key();
+        price_feed.authority = authority;
+        price_feed.price = 0;
+        price_feed.updated_slot = 0;
+        price_feed.bump = ctx.bumps.price_feed;
+        Ok(())
+    }
+
+    /// Publish a new price, in output base units per input base unit scaled
+    /// by `PRICE_SCALE` (price feed authority only)
+    pub fn update_price(ctx: Context<UpdatePrice>, price: u64) -> Result<()> {
+        require!(price > 0, ErrorCode::InvalidPrice);
+
+        let slot = Clock::get()?.slot;
+        let price_feed = &mut ctx.accounts.price_feed;
+        price_feed.price = price;
+        price_feed.updated_slot = slot;
+
+        emit!(PriceUpdatedEvent {
+            input_mint: price_feed.input_mint,
+            output_mint: price_feed.output_mint,
+            price,
+            slot,
+        });
+
+        Ok(())
+    }
+
+    /// Queue a swap validation priced by the pair's price feed
+    ///
+    /// Unlike `validate_swap`, the user only supplies the input amount and
+    /// their minimum output; the MXE computes the output from the feed's
+    /// price. The price must have been updated within `MAX_PRICE_AGE_SLOTS`.
+    ///
+    /// This is a quote, not a trade: neither balance is debited or credited.
+    /// Nothing on the other side holds the output tokens, so settling at the
+    /// oracle price would mint them. Use the constant-product pool to trade.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_input_amount` - Encrypted u64 (32 bytes)
+    /// * `encrypted_min_output` - Encrypted u64 (32 bytes)
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    pub fn validate_oracle_swap(
+        ctx: Context<ValidateOracleSwap>,
+        computation_offset: u64,
+        encrypted_input_amount: [u8; 32],
+        encrypted_min_output: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let price_feed = &ctx.accounts.price_feed;
+        require!(
+            price_feed.price > 0
+                && Clock::get()?.slot
+                    <= price_feed.updated_slot.saturating_add(MAX_PRICE_AGE_SLOTS),
+            ErrorCode::StalePrice
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::ValidateOracleSwap,
+            computation_offset,
+            &[],
+            ctx.bumps.computation_request,
+        )?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_input_amount)
+            .encrypted_u64(encrypted_min_output)
+            .plaintext_u128(ctx.accounts.balance_account.nonce)
+            .account(
+                ctx.accounts.balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.output_balance_account.nonce)
+            .account(
+                ctx.accounts.output_balance_account.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(ctx.accounts.price_feed.price)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ValidateOracleSwapCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.computation_request.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "validate_oracle_swap")]
+    pub fn validate_oracle_swap_callback(
+        ctx: Context<ValidateOracleSwapCallback>,
+        output: SignedComputationOutputs<ValidateOracleSwapOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(ValidateOracleSwapOutput { field_0 }) => {
+                // field_0.ciphertexts[0] = is_valid (bool)
+                // field_0.ciphertexts[1] = slippage_ok (bool)
+                // field_0.ciphertexts[2] = expected_output (u64)
+                emit!(OracleSwapValidationEvent {
+                    is_valid: field_0.ciphertexts[0],
+                    slippage_ok: field_0.ciphertexts[1],
+                    expected_output: field_0.ciphertexts[2],
+                    nonce: field_0.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // CONSTANT-PRODUCT POOL
+    // =========================================================================
+
+    /// Create a constant-product pool for a pair of mints (admin only)
+    ///
+    /// `mint_a` must sort before `mint_b`, so each pair has a single pool.
+    /// Queues the `init_pool` circuit, which writes MXE-encrypted zero
+    /// reserves; the pool cannot be used until the callback lands.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `fee_bps` - Swap fee in basis points of the input amount, kept by the pool
+    pub fn init_pool(ctx: Context<InitPool>, computation_offset: u64, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::InitPool,
+            computation_offset,
+            &[ctx.accounts.pool.key()],
+            ctx.bumps.computation_request,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.fee_bps = fee_bps;
+        pool.bump = ctx.bumps.pool;
+        pool.lock(computation_offset)?;
+
+        let args = ArgBuilder::new().build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitPoolCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pool.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_pool")]
+    pub fn init_pool_callback(
+        ctx: Context<InitPoolCallback>,
+        output: SignedComputationOutputs<InitPoolOutput>,
+    ) -> Result<()> {
+        // The pool never received its initial reserves; close it so the
+        // admin can retry `init_pool`
+        if matches!(output, SignedComputationOutputs::Failure) {
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .pool
+                .close(ctx.accounts.requester.to_account_info())?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(InitPoolOutput { field_0 }) => {
+                let pool_key = ctx.accounts.pool.key();
+                let pool = &mut ctx.accounts.pool;
+                pool.set_reserves(field_0.ciphertexts, field_0.nonce);
+                pool.is_initialized = true;
+
+                emit!(PoolCreatedEvent {
+                    pool: pool_key,
+                    mint_a: pool.mint_a,
+                    mint_b: pool.mint_b,
+                    fee_bps: pool.fee_bps,
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Move liquidity from the admin's encrypted balances into a pool (admin only)
+    ///
+    /// Liquidity is protocol-owned: there are no LP shares, and the amounts
+    /// stay encrypted. The MXE applies both amounts or neither. The admin
+    /// takes it back out with `remove_liquidity`.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amount_a` - Encrypted u64 (32 bytes), amount of `mint_a`
+    /// * `encrypted_amount_b` - Encrypted u64 (32 bytes), amount of `mint_b`
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        computation_offset: u64,
+        encrypted_amount_a: [u8; 32],
+        encrypted_amount_b: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::AddLiquidity,
+            computation_offset,
+            &[
+                ctx.accounts.provider_balance_a.key(),
+                ctx.accounts.provider_balance_b.key(),
+                ctx.accounts.pool.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.provider_balance_a.lock(computation_offset)?;
+        ctx.accounts.provider_balance_b.lock(computation_offset)?;
+        ctx.accounts.pool.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount_a)
+            .encrypted_u64(encrypted_amount_b)
+            .plaintext_u128(ctx.accounts.provider_balance_a.nonce)
+            .account(
+                ctx.accounts.provider_balance_a.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.provider_balance_b.nonce)
+            .account(
+                ctx.accounts.provider_balance_b.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.pool.nonce)
+            .account(
+                ctx.accounts.pool.key(),
+                POOL_RESERVES_OFFSET,
+                POOL_RESERVES_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AddLiquidityCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.provider_balance_a.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.provider_balance_b.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pool.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "add_liquidity")]
+    pub fn add_liquidity_callback(
+        ctx: Context<AddLiquidityCallback>,
+        output: SignedComputationOutputs<AddLiquidityOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.provider_balance_a.unlock();
+            ctx.accounts.provider_balance_b.unlock();
+            ctx.accounts.pool.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(AddLiquidityOutput { field_0 }) => {
+                // field_0.field_0 = is_valid for the provider (Enc<Shared, bool>)
+                // field_0.field_1 = updated provider mint_a balance (Enc<Mxe, u64>)
+                // field_0.field_2 = updated provider mint_b balance (Enc<Mxe, u64>)
+                // field_0.field_3 = updated reserves (Enc<Mxe, PoolReserves>)
+                let result = field_0.field_0;
+                let new_balance_a = field_0.field_1;
+                let new_balance_b = field_0.field_2;
+                let new_reserves = field_0.field_3;
+
+                ctx.accounts
+                    .provider_balance_a
+                    .set_balance(new_balance_a.ciphertexts[0], new_balance_a.nonce);
+                ctx.accounts
+                    .provider_balance_b
+                    .set_balance(new_balance_b.ciphertexts[0], new_balance_b.nonce);
+                ctx.accounts
+                    .pool
+                    .set_reserves(new_reserves.ciphertexts, new_reserves.nonce);
+
+                emit!(LiquidityAddedEvent {
+                    pool: ctx.accounts.pool.key(),
+                    is_valid: result.ciphertexts[0],
+                    nonce: result.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Move liquidity from a pool back into the admin's encrypted balances (admin only)
+    ///
+    /// The exit for protocol-owned liquidity. Whoever is admin at the time
+    /// receives it, so liquidity added by a previous admin follows the role.
+    /// The MXE applies both amounts or neither, and rejects amounts larger
+    /// than the reserves. Allowed while the program is paused.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amount_a` - Encrypted u64 (32 bytes), amount of `mint_a`
+    /// * `encrypted_amount_b` - Encrypted u64 (32 bytes), amount of `mint_b`
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        computation_offset: u64,
+        encrypted_amount_a: [u8; 32],
+        encrypted_amount_b: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::RemoveLiquidity,
+            computation_offset,
+            &[
+                ctx.accounts.provider_balance_a.key(),
+                ctx.accounts.provider_balance_b.key(),
+                ctx.accounts.pool.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.provider_balance_a.lock(computation_offset)?;
+        ctx.accounts.provider_balance_b.lock(computation_offset)?;
+        ctx.accounts.pool.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount_a)
+            .encrypted_u64(encrypted_amount_b)
+            .plaintext_u128(ctx.accounts.provider_balance_a.nonce)
+            .account(
+                ctx.accounts.provider_balance_a.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.provider_balance_b.nonce)
+            .account(
+                ctx.accounts.provider_balance_b.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.pool.nonce)
+            .account(
+                ctx.accounts.pool.key(),
+                POOL_RESERVES_OFFSET,
+                POOL_RESERVES_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RemoveLiquidityCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.provider_balance_a.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.provider_balance_b.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pool.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "remove_liquidity")]
+    pub fn remove_liquidity_callback(
+        ctx: Context<RemoveLiquidityCallback>,
+        output: SignedComputationOutputs<RemoveLiquidityOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.provider_balance_a.unlock();
+            ctx.accounts.provider_balance_b.unlock();
+            ctx.accounts.pool.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(RemoveLiquidityOutput { field_0 }) => {
+                // field_0.field_0 = is_valid for the provider (Enc<Shared, bool>)
+                // field_0.field_1 = updated provider mint_a balance (Enc<Mxe, u64>)
+                // field_0.field_2 = updated provider mint_b balance (Enc<Mxe, u64>)
+                // field_0.field_3 = updated reserves (Enc<Mxe, PoolReserves>)
+                let result = field_0.field_0;
+                let new_balance_a = field_0.field_1;
+                let new_balance_b = field_0.field_2;
+                let new_reserves = field_0.field_3;
+
+                ctx.accounts
+                    .provider_balance_a
+                    .set_balance(new_balance_a.ciphertexts[0], new_balance_a.nonce);
+                ctx.accounts
+                    .provider_balance_b
+                    .set_balance(new_balance_b.ciphertexts[0], new_balance_b.nonce);
+                ctx.accounts
+                    .pool
+                    .set_reserves(new_reserves.ciphertexts, new_reserves.nonce);
+
+                emit!(LiquidityRemovedEvent {
+                    pool: ctx.accounts.pool.key(),
+                    is_valid: result.ciphertexts[0],
+                    nonce: result.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Queue a swap against a pool's encrypted reserves
+    ///
+    /// The MXE reads the trader's balances for both of the pool's mints and
+    /// the reserves, prices the trade on the `x * y = k` curve after the
+    /// pool's fee, and the callback writes all three back. The amount, the
+    /// minimum output and even the direction stay encrypted; a rejected swap
+    /// returns every balance unchanged.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amount_in` - Encrypted u64 (32 bytes)
+    /// * `encrypted_min_amount_out` - Encrypted u64 (32 bytes)
+    /// * `encrypted_a_to_b` - Encrypted bool (32 bytes), true to sell `mint_a` for `mint_b`
+    /// * `pubkey` - X25519 public key for result encryption
+    /// * `nonce` - Encryption nonce
+    pub fn swap_cpmm(
+        ctx: Context<SwapCpmm>,
+        computation_offset: u64,
+        encrypted_amount_in: [u8; 32],
+        encrypted_min_amount_out: [u8; 32],
+        encrypted_a_to_b: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::SwapCpmm,
+            computation_offset,
+            &[
+                ctx.accounts.trader_balance_a.key(),
+                ctx.accounts.trader_balance_b.key(),
+                ctx.accounts.pool.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.trader_balance_a.lock(computation_offset)?;
+        ctx.accounts.trader_balance_b.lock(computation_offset)?;
+        ctx.accounts.pool.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount_in)
+            .encrypted_u64(encrypted_min_amount_out)
+            .encrypted_bool(encrypted_a_to_b)
+            .plaintext_u128(ctx.accounts.trader_balance_a.nonce)
+            .account(
+                ctx.accounts.trader_balance_a.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.trader_balance_b.nonce)
+            .account(
+                ctx.accounts.trader_balance_b.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.pool.nonce)
+            .account(
+                ctx.accounts.pool.key(),
+                POOL_RESERVES_OFFSET,
+                POOL_RESERVES_LEN,
+            )
+            .plaintext_u16(ctx.accounts.pool.fee_bps)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SwapCpmmCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.trader_balance_a.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.trader_balance_b.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pool.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for a pool swap
+    ///
+    /// The circuit returns the trader's balances and the reserves unchanged
+    /// when the swap is rejected, so they are always written together.
+    #[arcium_callback(encrypted_ix = "swap_cpmm")]
+    pub fn swap_cpmm_callback(
+        ctx: Context<SwapCpmmCallback>,
+        output: SignedComputationOutputs<SwapCpmmOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.trader_balance_a.unlock();
+            ctx.accounts.trader_balance_b.unlock();
+            ctx.accounts.pool.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SwapCpmmOutput { field_0 }) => {
+                // field_0.field_0 = result for the trader (Enc<Shared, _>)
+                // field_0.field_1 = updated trader mint_a balance (Enc<Mxe, u64>)
+                // field_0.field_2 = updated trader mint_b balance (Enc<Mxe, u64>)
+                // field_0.field_3 = updated reserves (Enc<Mxe, PoolReserves>)
+                let result = field_0.field_0;
+                let new_balance_a = field_0.field_1;
+                let new_balance_b = field_0.field_2;
+                let new_reserves = field_0.field_3;
+
+                ctx.accounts
+                    .trader_balance_a
+                    .set_balance(new_balance_a.ciphertexts[0], new_balance_a.nonce);
+                ctx.accounts
+                    .trader_balance_b
+                    .set_balance(new_balance_b.ciphertexts[0], new_balance_b.nonce);
+                ctx.accounts
+                    .pool
+                    .set_reserves(new_reserves.ciphertexts, new_reserves.nonce);
+
+                // result.ciphertexts[0] = is_valid (bool)
+                // result.ciphertexts[1] = amount_out (u64)
+                // result.ciphertexts[2] = fee (u64)
+                emit!(CpmmSwapEvent {
+                    pool: ctx.accounts.pool.key(),
+                    is_valid: result.ciphertexts[0],
+                    amount_out: result.ciphertexts[1],
+                    fee: result.ciphertexts[2],
+                    nonce: result.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // LIMIT ORDERS
+    // =========================================================================
+
+    /// Place a limit order with a hidden side, price and size
+    ///
+    /// Queues the `place_order` circuit, which moves the order's escrow out
+    /// of the owner's spendable balance (the base size for a sell, the quote
+    /// notional for a buy) and re-encrypts the order for the MXE. An order
+    /// the balance cannot cover is stored empty and never fills. The order
+    /// cannot be matched until the callback lands. Fill notices are
+    /// encrypted to `pubkey`.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `order_id` - Caller-chosen id, unique per owner
+    /// * `encrypted_is_buy` - Encrypted bool (32 bytes), true to buy the base mint
+    /// * `encrypted_price` - Encrypted u64 (32 bytes), quote per base unit scaled by `PRICE_SCALE`
+    /// * `encrypted_size` - Encrypted u64 (32 bytes), size in base units
+    /// * `pubkey` - X25519 public key for fill notices
+    /// * `nonce` - Encryption nonce
+    /// * `matcher` - Wallet allowed to match the order
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_order(
+        ctx: Context<PlaceOrder>,
+        computation_offset: u64,
+        order_id: u64,
+        encrypted_is_buy: [u8; 32],
+        encrypted_price: [u8; 32],
+        encrypted_size: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        matcher: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::PlaceOrder,
+            computation_offset,
+            &[
+                ctx.accounts.order.key(),
+                ctx.accounts.base_balance.key(),
+                ctx.accounts.quote_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.base_balance.lock(computation_offset)?;
+        ctx.accounts.quote_balance.lock(computation_offset)?;
+
+        let order = &mut ctx.accounts.order;
+        order.owner = ctx.accounts.payer.key();
+        order.base_mint = ctx.accounts.base_mint.key();
+        order.quote_mint = ctx.accounts.quote_mint.key();
+        order.order_id = order_id;
+        order.encryption_pubkey = pubkey;
+        order.matcher = matcher;
+        order.bump = ctx.bumps.order;
+        order.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_bool(encrypted_is_buy)
+            .encrypted_u64(encrypted_price)
+            .encrypted_u64(encrypted_size)
+            .plaintext_u128(ctx.accounts.base_balance.nonce)
+            .account(
+                ctx.accounts.base_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.quote_balance.nonce)
+            .account(
+                ctx.accounts.quote_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![PlaceOrderCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.order.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.base_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.quote_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for placing an order
+    ///
+    /// The circuit returns both balances unchanged when the order is
+    /// rejected, so the order and the balances are always written together.
+    #[arcium_callback(encrypted_ix = "place_order")]
+    pub fn place_order_callback(
+        ctx: Context<PlaceOrderCallback>,
+        output: SignedComputationOutputs<PlaceOrderOutput>,
+    ) -> Result<()> {
+        // The order never received its encrypted state; close it so the
+        // owner can place it again
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.base_balance.unlock();
+            ctx.accounts.quote_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .order
+                .close(ctx.accounts.owner.to_account_info())?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(PlaceOrderOutput { field_0 }) => {
+                // field_0.field_0 = whether the order was accepted (Enc<Shared, bool>)
+                // field_0.field_1 = escrowed order (Enc<Mxe, OrderState>)
+                // field_0.field_2 = updated base balance (Enc<Mxe, u64>)
+                // field_0.field_3 = updated quote balance (Enc<Mxe, u64>)
+                let is_valid = field_0.field_0;
+                let new_order = field_0.field_1;
+                let new_base_balance = field_0.field_2;
+                let new_quote_balance = field_0.field_3;
+
+                ctx.accounts
+                    .base_balance
+                    .set_balance(new_base_balance.ciphertexts[0], new_base_balance.nonce);
+                ctx.accounts
+                    .quote_balance
+                    .set_balance(new_quote_balance.ciphertexts[0], new_quote_balance.nonce);
+
+                let order_key = ctx.accounts.order.key();
+                let order = &mut ctx.accounts.order;
+                order.set_state(new_order.ciphertexts, new_order.nonce);
+                order.is_initialized = true;
+
+                emit!(OrderPlacedEvent {
+                    order: order_key,
+                    owner: order.owner,
+                    base_mint: order.base_mint,
+                    quote_mint: order.quote_mint,
+                    is_valid: is_valid.ciphertexts[0],
+                    nonce: is_valid.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Queue a settlement of an order's proceeds into the owner's balances
+    ///
+    /// Credits everything the order has received from fills to the owner's
+    /// spendable balance for that mint. With `close`, the unfilled escrow is
+    /// refunded too and the callback closes the order, which is how an order
+    /// is cancelled. Only the owner may settle, and it stays open while the
+    /// program is paused so funds can always be withdrawn.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `close` - Also refund the escrow and close the order
+    pub fn settle_order(
+        ctx: Context<SettleOrder>,
+        computation_offset: u64,
+        close: bool,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.owner.key(),
+            CircuitKind::SettleOrder,
+            computation_offset,
+            &[
+                ctx.accounts.order.key(),
+                ctx.accounts.base_balance.key(),
+                ctx.accounts.quote_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.order.lock(computation_offset)?;
+        ctx.accounts.order.closing = close;
+        ctx.accounts.base_balance.lock(computation_offset)?;
+        ctx.accounts.quote_balance.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.order.nonce)
+            .account(
+                ctx.accounts.order.key(),
+                ORDER_STATE_OFFSET,
+                ORDER_STATE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.base_balance.nonce)
+            .account(
+                ctx.accounts.base_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.quote_balance.nonce)
+            .account(
+                ctx.accounts.quote_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_bool(close)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SettleOrderCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.order.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.base_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.quote_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.owner.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for an order settlement
+    ///
+    /// The order and both balances are always written back; the circuit
+    /// leaves them unchanged if a credit would overflow. A settled closing
+    /// order is then closed to its owner.
+    #[arcium_callback(encrypted_ix = "settle_order")]
+    pub fn settle_order_callback(
+        ctx: Context<SettleOrderCallback>,
+        output: SignedComputationOutputs<SettleOrderOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.order.unlock();
+            ctx.accounts.order.closing = false;
+            ctx.accounts.base_balance.unlock();
+            ctx.accounts.quote_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SettleOrderOutput { field_0 }) => {
+                // field_0.field_0 = updated order (Enc<Mxe, OrderState>)
+                // field_0.field_1 = updated base balance (Enc<Mxe, u64>)
+                // field_0.field_2 = updated quote balance (Enc<Mxe, u64>)
+                // field_0.field_3 = whether the credits were applied (revealed bool)
+                let new_order = field_0.field_0;
+                let new_base_balance = field_0.field_1;
+                let new_quote_balance = field_0.field_2;
+                let settled = field_0.field_3;
+
+                ctx.accounts
+                    .base_balance
+                    .set_balance(new_base_balance.ciphertexts[0], new_base_balance.nonce);
+                ctx.accounts
+                    .quote_balance
+                    .set_balance(new_quote_balance.ciphertexts[0], new_quote_balance.nonce);
+
+                let order_key = ctx.accounts.order.key();
+                let order = &mut ctx.accounts.order;
+                order.set_state(new_order.ciphertexts, new_order.nonce);
+                let closed = settled && order.closing;
+                order.closing = false;
+
+                emit!(OrderSettledEvent {
+                    order: order_key,
+                    owner: order.owner,
+                    settled,
+                    closed,
+                });
+
+                if closed {
+                    ctx.accounts
+                        .order
+                        .close(ctx.accounts.owner.to_account_info())?;
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Queue a match between two resting orders on the same pair
+    ///
+    /// Only the matcher both orders named at placement can submit them, so
+    /// no one else can lock an order or probe its hidden price. The MXE
+    /// decides whether they cross and fills both by the smaller remaining
+    /// size at the sell price, moving value between the two orders' escrow
+    /// and proceeds. Whether they crossed is encrypted to the matcher; the
+    /// chain learns nothing.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `pubkey` - Matcher's X25519 public key for the match outcome
+    pub fn match_orders(
+        ctx: Context<MatchOrders>,
+        computation_offset: u64,
+        pubkey: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::MatchOrders,
+            computation_offset,
+            &[ctx.accounts.order_a.key(), ctx.accounts.order_b.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.order_a.lock(computation_offset)?;
+        ctx.accounts.order_b.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.order_a.nonce)
+            .account(
+                ctx.accounts.order_a.key(),
+                ORDER_STATE_OFFSET,
+                ORDER_STATE_LEN,
+            )
+            .x25519_pubkey(ctx.accounts.order_a.encryption_pubkey)
+            .plaintext_u128(ctx.accounts.order_b.nonce)
+            .account(
+                ctx.accounts.order_b.key(),
+                ORDER_STATE_OFFSET,
+                ORDER_STATE_LEN,
+            )
+            .x25519_pubkey(ctx.accounts.order_b.encryption_pubkey)
+            .x25519_pubkey(pubkey)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![MatchOrdersCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.order_a.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.order_b.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for an order match
+    ///
+    /// Both orders are always written back; the circuit returns them
+    /// unchanged when they do not cross. Fill notices are emitted either way
+    /// so the logs do not reveal whether the orders crossed.
+    #[arcium_callback(encrypted_ix = "match_orders")]
+    pub fn match_orders_callback(
+        ctx: Context<MatchOrdersCallback>,
+        output: SignedComputationOutputs<MatchOrdersOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.order_a.unlock();
+            ctx.accounts.order_b.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(MatchOrdersOutput { field_0 }) => {
+                // field_0.field_0 = updated order_a (Enc<Mxe, OrderState>)
+                // field_0.field_1 = updated order_b (Enc<Mxe, OrderState>)
+                // field_0.field_2 = fill notice for order_a's owner (Enc<Shared, _>)
+                // field_0.field_3 = fill notice for order_b's owner (Enc<Shared, _>)
+                // field_0.field_4 = whether the orders crossed (Enc<Shared, bool>)
+                let new_order_a = field_0.field_0;
+                let new_order_b = field_0.field_1;
+                let fill_a = field_0.field_2;
+                let fill_b = field_0.field_3;
+                let matched = field_0.field_4;
+
+                ctx.accounts
+                    .order_a
+                    .set_state(new_order_a.ciphertexts, new_order_a.nonce);
+                ctx.accounts
+                    .order_b
+                    .set_state(new_order_b.ciphertexts, new_order_b.nonce);
+
+                let order_a = &ctx.accounts.order_a;
+                let order_b = &ctx.accounts.order_b;
+                emit!(OrdersMatchedEvent {
+                    order_a: order_a.key(),
+                    order_b: order_b.key(),
+                    matched: matched.ciphertexts[0],
+                    nonce: matched.nonce.to_le_bytes(),
+                });
+
+                // fill.ciphertexts[0] = filled (u64)
+                // fill.ciphertexts[1] = remaining (u64)
+                // fill.ciphertexts[2] = proceeds (u64)
+                for (order, fill) in [(order_a, fill_a), (order_b, fill_b)] {
+                    emit!(OrderFilledEvent {
+                        order: order.key(),
+                        owner: order.owner,
+                        filled: fill.ciphertexts[0],
+                        remaining: fill.ciphertexts[1],
+                        proceeds: fill.ciphertexts[2],
+                        nonce: fill.nonce.to_le_bytes(),
+                    });
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // BATCH AUCTION
+    // =========================================================================
+
+    /// Clear a batch of resting orders for one pair at a single price
+    ///
+    /// Only the matcher every order named at placement can submit the batch,
+    /// so no one else can lock the orders or probe their prices. The MXE
+    /// picks the price that crosses the most volume and fills every eligible
+    /// order at it, moving value between the orders' escrow and proceeds.
+    /// Whether anything cleared is encrypted to the matcher.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `pubkey` - Matcher's X25519 public key for the auction outcome
+    pub fn batch_auction(
+        ctx: Context<BatchAuction>,
+        computation_offset: u64,
+        pubkey: [u8; 32],
+    ) -> Result<()> {
+        let order_keys = ctx.accounts.order_keys();
+        for (i, key) in order_keys.iter().enumerate() {
+            require!(!order_keys[..i].contains(key), ErrorCode::DuplicateOrder);
+        }
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::BatchAuction,
+            computation_offset,
+            &order_keys,
+            ctx.bumps.computation_request,
+        )?;
+        for order in ctx.accounts.orders_mut() {
+            order.lock(computation_offset)?;
+        }
+
+        // Orders in slot order, followed by their owners' keys for the fill
+        // notices and the matcher's key for the outcome
+        let orders = ctx.accounts.orders();
+        let mut args = ArgBuilder::new();
+        for (order, key) in orders.iter().zip(order_keys) {
+            args =
+                args.plaintext_u128(order.nonce)
+                    .account(key, ORDER_STATE_OFFSET, ORDER_STATE_LEN);
+        }
+        for order in orders {
+            args = args.x25519_pubkey(order.encryption_pubkey);
+        }
+        let args = args.x25519_pubkey(pubkey).build();
+
+        let mut callback_accounts = vec![CallbackAccount {
+            pubkey: ctx.accounts.computation_request.key(),
+            is_writable: true,
+        }];
+        callback_accounts.extend(order_keys.map(|pubkey| CallbackAccount {
+            pubkey,
+            is_writable: true,
+        }));
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![BatchAuctionCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for a batch auction
+    ///
+    /// Every order is always written back; the circuit returns them unchanged
+    /// when nothing crosses. Fill notices are emitted either way so the logs
+    /// do not reveal whether the batch cleared.
+    #[arcium_callback(encrypted_ix = "batch_auction")]
+    pub fn batch_auction_callback(
+        ctx: Context<BatchAuctionCallback>,
+        output: SignedComputationOutputs<BatchAuctionOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            for order in ctx.accounts.orders_mut() {
+                order.unlock();
+            }
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(BatchAuctionOutput { field_0 }) => {
+                // field_0.field_0 = updated orders, in slot order
+                // field_0.field_1 = fill notices for the owners, in slot order
+                // field_0.field_2 = whether anything cleared (Enc<Shared, bool>)
+                let new_orders = field_0.field_0;
+                let fills = field_0.field_1;
+                let cleared = field_0.field_2;
+
+                for (order, new_order) in ctx.accounts.orders_mut().into_iter().zip(new_orders) {
+                    order.set_state(new_order.ciphertexts, new_order.nonce);
+                }
+
+                emit!(AuctionClearedEvent {
+                    orders: ctx.accounts.order_keys(),
+                    cleared: cleared.ciphertexts[0],
+                    nonce: cleared.nonce.to_le_bytes(),
+                });
+
+                // fill.ciphertexts[0] = filled (u64)
+                // fill.ciphertexts[1] = clearing_price (u64)
+                // fill.ciphertexts[2] = remaining (u64)
+                // fill.ciphertexts[3] = proceeds (u64)
+                let order_keys = ctx.accounts.order_keys();
+                for ((order, key), fill) in
+                    ctx.accounts.orders().into_iter().zip(order_keys).zip(fills)
+                {
+                    emit!(AuctionFillEvent {
+                        order: key,
+                        owner: order.owner,
+                        filled: fill.ciphertexts[0],
+                        clearing_price: fill.ciphertexts[1],
+                        remaining: fill.ciphertexts[2],
+                        proceeds: fill.ciphertexts[3],
+                        nonce: fill.nonce.to_le_bytes(),
+                    });
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // SEALED-BID AUCTION
+    // =========================================================================
+
+    /// Open a sealed-bid auction selling `lot_amount` of `lot_mint` for `bid_mint`
+    ///
+    /// Queues the `init_auction` circuit, which moves the lot out of the
+    /// seller's encrypted balance into escrow and writes an MXE-encrypted
+    /// empty standing; bids are rejected until the callback lands. If the
+    /// balance cannot cover the lot, the callback closes the auction instead.
+    /// With `second_price` set the winner pays the second-highest bid
+    /// (Vickrey), otherwise their own; either way never less than
+    /// `reserve_price`, and bids below it never win.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `auction_id` - Caller-chosen id, unique per seller
+    /// * `second_price` - Clear at the second-highest bid instead of the highest
+    /// * `lot_amount` - Amount of `lot_mint` being sold
+    /// * `end_slot` - First slot at which bidding is closed and the auction can be resolved
+    /// * `reserve_price` - Lowest price in `bid_mint` the lot sells for
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        computation_offset: u64,
+        auction_id: u64,
+        second_price: bool,
+        lot_amount: u64,
+        end_slot: u64,
+        reserve_price: u64,
+    ) -> Result<()> {
+        require!(end_slot > Clock::get()?.slot, ErrorCode::AuctionEndPassed);
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::InitAuction,
+            computation_offset,
+            &[
+                ctx.accounts.auction.key(),
+                ctx.accounts.seller_lot_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.seller_lot_balance.lock(computation_offset)?;
+
+        let auction = &mut ctx.accounts.auction;
+        auction.seller = ctx.accounts.payer.key();
+        auction.lot_mint = ctx.accounts.lot_mint.key();
+        auction.bid_mint = ctx.accounts.bid_mint.key();
+        auction.auction_id = auction_id;
+        auction.second_price = second_price;
+        auction.lot_amount = lot_amount;
+        auction.end_slot = end_slot;
+        auction.reserve_price = reserve_price;
+        auction.bump = ctx.bumps.auction;
+        auction.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.seller_lot_balance.nonce)
+            .account(
+                ctx.accounts.seller_lot_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u64(lot_amount)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitAuctionCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.auction.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.seller_lot_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for opening an auction
+    ///
+    /// The seller's lot balance is always written back; the circuit leaves it
+    /// unchanged if it could not cover the lot, in which case the auction is
+    /// closed to the seller rather than opened.
+    #[arcium_callback(encrypted_ix = "init_auction")]
+    pub fn init_auction_callback(
+        ctx: Context<InitAuctionCallback>,
+        output: SignedComputationOutputs<InitAuctionOutput>,
+    ) -> Result<()> {
+        // The auction never received its initial standing; close it so the
+        // seller can create it again
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.seller_lot_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .auction
+                .close(ctx.accounts.seller.to_account_info())?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(InitAuctionOutput { field_0 }) => {
+                // field_0.field_0 = empty standing (Enc<Mxe, SealedBids>)
+                // field_0.field_1 = updated seller lot balance (Enc<Mxe, u64>)
+                // field_0.field_2 = whether the lot was escrowed (revealed bool)
+                let bids = field_0.field_0;
+                let new_lot_balance = field_0.field_1;
+                let escrowed = field_0.field_2;
+
+                ctx.accounts
+                    .seller_lot_balance
+                    .set_balance(new_lot_balance.ciphertexts[0], new_lot_balance.nonce);
+
+                if escrowed {
+                    let auction_key = ctx.accounts.auction.key();
+                    let auction = &mut ctx.accounts.auction;
+                    auction.set_bids(bids.ciphertexts, bids.nonce);
+                    auction.is_initialized = true;
+
+                    emit!(SealedAuctionCreatedEvent {
+                        auction: auction_key,
+                        seller: auction.seller,
+                        lot_mint: auction.lot_mint,
+                        bid_mint: auction.bid_mint,
+                        second_price: auction.second_price,
+                        lot_amount: auction.lot_amount,
+                        end_slot: auction.end_slot,
+                        reserve_price: auction.reserve_price,
+                    });
+                } else {
+                    ctx.accounts
+                        .auction
+                        .close(ctx.accounts.seller.to_account_info())?;
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Place a sealed bid on an open auction
+    ///
+    /// The bid takes the next index in submission order. The `submit_bid`
+    /// circuit moves it out of the bidder's `bid_mint` balance into the
+    /// bidder's `SealedBid` escrow and folds it into the encrypted standing;
+    /// a bid the balance cannot cover is folded in as zero, and whether it
+    /// was accepted is encrypted to the bidder. Bids are serialized: the
+    /// auction is locked until the callback lands. A bid whose computation
+    /// aborts keeps its index but never wins. Each wallet bids once, before
+    /// the auction's end slot.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `encrypted_amount` - Encrypted u64 (32 bytes), bid in `bid_mint`
+    /// * `pubkey` - X25519 public key the bid was encrypted with
+    /// * `nonce` - Encryption nonce
+    pub fn submit_bid(
+        ctx: Context<SubmitBid>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        require!(
+            Clock::get()?.slot < ctx.accounts.auction.end_slot,
+            ErrorCode::AuctionEnded
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::SubmitBid,
+            computation_offset,
+            &[
+                ctx.accounts.auction.key(),
+                ctx.accounts.bid.key(),
+                ctx.accounts.bidder_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.bidder_balance.lock(computation_offset)?;
+
+        let auction = &mut ctx.accounts.auction;
+        let bid_index = auction.bidders.len() as u8;
+        auction.bidders.push(ctx.accounts.payer.key());
+        auction.lock(computation_offset)?;
+
+        ctx.accounts.bid.open(
+            ctx.accounts.auction.key(),
+            ctx.accounts.payer.key(),
+            bid_index,
+            computation_offset,
+            ctx.bumps.bid,
+        );
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount)
+            .plaintext_u128(ctx.accounts.auction.nonce)
+            .account(
+                ctx.accounts.auction.key(),
+                SEALED_BIDS_OFFSET,
+                SEALED_BIDS_LEN,
+            )
+            .plaintext_u128(ctx.accounts.bidder_balance.nonce)
+            .account(
+                ctx.accounts.bidder_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u8(bid_index)
+            .plaintext_u64(ctx.accounts.auction.reserve_price)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SubmitBidCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.auction.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bid.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bidder_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "submit_bid")]
+    pub fn submit_bid_callback(
+        ctx: Context<SubmitBidCallback>,
+        output: SignedComputationOutputs<SubmitBidOutput>,
+    ) -> Result<()> {
+        // Nothing was escrowed; close the bid so the bidder can bid again
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.auction.unlock();
+            ctx.accounts.bidder_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .bid
+                .close(ctx.accounts.bidder.to_account_info())?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SubmitBidOutput { field_0 }) => {
+                // field_0.field_0 = whether the balance covered the bid (Enc<Shared, bool>)
+                // field_0.field_1 = updated standing (Enc<Mxe, SealedBids>)
+                // field_0.field_2 = escrowed bid, zero if not covered (Enc<Mxe, u64>)
+                // field_0.field_3 = updated bidder balance (Enc<Mxe, u64>)
+                let is_valid = field_0.field_0;
+                let bids = field_0.field_1;
+                let escrow = field_0.field_2;
+                let new_balance = field_0.field_3;
+
+                ctx.accounts
+                    .bidder_balance
+                    .set_balance(new_balance.ciphertexts[0], new_balance.nonce);
+                ctx.accounts
+                    .bid
+                    .set_escrow(escrow.ciphertexts[0], escrow.nonce);
+
+                let auction_key = ctx.accounts.auction.key();
+                let auction = &mut ctx.accounts.auction;
+                auction.set_bids(bids.ciphertexts, bids.nonce);
+
+                emit!(SealedBidSubmittedEvent {
+                    auction: auction_key,
+                    bidder: ctx.accounts.bid.bidder,
+                    bid_index: ctx.accounts.bid.bid_index,
+                    is_valid: is_valid.ciphertexts[0],
+                    nonce: is_valid.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Reveal the winner and clearing price once the auction has ended
+    ///
+    /// Anyone may resolve from the auction's end slot on, so neither the
+    /// seller nor a bidder can hold escrowed funds hostage. Only the winning
+    /// bid's index and the price it clears at are revealed; every other bid
+    /// stays encrypted. The result is recorded on the auction, after which
+    /// `settle_bid` and `settle_auction` pay out the escrow.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    pub fn resolve_auction(ctx: Context<ResolveAuction>, computation_offset: u64) -> Result<()> {
+        require!(
+            Clock::get()?.slot >= ctx.accounts.auction.end_slot,
+            ErrorCode::AuctionNotEnded
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::ResolveAuction,
+            computation_offset,
+            &[ctx.accounts.auction.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.auction.lock(computation_offset)?;
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.auction.nonce)
+            .account(
+                ctx.accounts.auction.key(),
+                SEALED_BIDS_OFFSET,
+                SEALED_BIDS_LEN,
+            )
+            .plaintext_bool(ctx.accounts.auction.second_price)
+            .plaintext_u64(ctx.accounts.auction.reserve_price)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ResolveAuctionCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.auction.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "resolve_auction")]
+    pub fn resolve_auction_callback(
+        ctx: Context<ResolveAuctionCallback>,
+        output: SignedComputationOutputs<ResolveAuctionOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.auction.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(ResolveAuctionOutput { field_0 }) => {
+                // field_0.field_0 = winning bid index, NO_BID if none (revealed u8)
+                // field_0.field_1 = clearing price (revealed u64)
+                let winner_index = field_0.field_0;
+                let clearing_price = field_0.field_1;
+
+                let auction_key = ctx.accounts.auction.key();
+                let auction = &mut ctx.accounts.auction;
+                auction.winner = auction.bidders.get(winner_index as usize).copied();
+                auction.clearing_price = clearing_price;
+                auction.resolved = true;
+                auction.unlock();
+
+                emit!(SealedAuctionResolvedEvent {
+                    auction: auction_key,
+                    winner: auction.winner,
+                    winner_index,
+                    clearing_price,
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Queue a payout of a bidder's escrow once the auction is resolved
+    ///
+    /// The winner is refunded whatever their escrow exceeds the clearing
+    /// price by and credited the lot; every other bidder is refunded in full.
+    /// The callback closes the bid. Only the bidder may settle, and it stays
+    /// open while the program is paused so escrow can always be recovered.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    pub fn settle_bid(ctx: Context<SettleBid>, computation_offset: u64) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.bidder.key(),
+            CircuitKind::SettleBid,
+            computation_offset,
+            &[
+                ctx.accounts.bid.key(),
+                ctx.accounts.bid_balance.key(),
+                ctx.accounts.lot_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.bid.lock(computation_offset)?;
+        ctx.accounts.bid_balance.lock(computation_offset)?;
+        ctx.accounts.lot_balance.lock(computation_offset)?;
+
+        let auction = &ctx.accounts.auction;
+        let won = auction.winner == Some(ctx.accounts.bidder.key());
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.bid.nonce)
+            .account(
+                ctx.accounts.bid.key(),
+                BID_ESCROW_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.bid_balance.nonce)
+            .account(
+                ctx.accounts.bid_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.lot_balance.nonce)
+            .account(
+                ctx.accounts.lot_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_bool(won)
+            .plaintext_u64(auction.clearing_price)
+            .plaintext_u64(auction.lot_amount)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SettleBidCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bid.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bid_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.lot_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bidder.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for a bid settlement
+    ///
+    /// Both balances are always written back; the circuit leaves them
+    /// unchanged if the escrow cannot cover the price or a credit would
+    /// overflow. A settled bid is then closed to its bidder.
+    #[arcium_callback(encrypted_ix = "settle_bid")]
+    pub fn settle_bid_callback(
+        ctx: Context<SettleBidCallback>,
+        output: SignedComputationOutputs<SettleBidOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.bid.unlock();
+            ctx.accounts.bid_balance.unlock();
+            ctx.accounts.lot_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SettleBidOutput { field_0 }) => {
+                // field_0.field_0 = updated bid-mint balance (Enc<Mxe, u64>)
+                // field_0.field_1 = updated lot-mint balance (Enc<Mxe, u64>)
+                // field_0.field_2 = whether the payout was applied (revealed bool)
+                let new_bid_balance = field_0.field_0;
+                let new_lot_balance = field_0.field_1;
+                let settled = field_0.field_2;
+
+                ctx.accounts
+                    .bid_balance
+                    .set_balance(new_bid_balance.ciphertexts[0], new_bid_balance.nonce);
+                ctx.accounts
+                    .lot_balance
+                    .set_balance(new_lot_balance.ciphertexts[0], new_lot_balance.nonce);
+
+                emit!(SealedBidSettledEvent {
+                    auction: ctx.accounts.bid.auction,
+                    bidder: ctx.accounts.bid.bidder,
+                    settled,
+                });
+
+                if settled {
+                    ctx.accounts
+                        .bid
+                        .close(ctx.accounts.bidder.to_account_info())?;
+                } else {
+                    ctx.accounts.bid.unlock();
+                }
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    /// Queue the seller's payout once the auction is resolved
+    ///
+    /// Credits the clearing price to the seller's `bid_mint` balance if the
+    /// lot sold, or returns the escrowed lot to their `lot_mint` balance if
+    /// no bid won. Only the seller may settle, once, and it stays open while
+    /// the program is paused.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    pub fn settle_auction(ctx: Context<SettleAuction>, computation_offset: u64) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.seller.key(),
+            CircuitKind::SettleAuction,
+            computation_offset,
+            &[
+                ctx.accounts.auction.key(),
+                ctx.accounts.bid_balance.key(),
+                ctx.accounts.lot_balance.key(),
+            ],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.auction.lock(computation_offset)?;
+        ctx.accounts.bid_balance.lock(computation_offset)?;
+        ctx.accounts.lot_balance.lock(computation_offset)?;
+
+        let auction = &ctx.accounts.auction;
+        let args = ArgBuilder::new()
+            .plaintext_u128(ctx.accounts.bid_balance.nonce)
+            .account(
+                ctx.accounts.bid_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_u128(ctx.accounts.lot_balance.nonce)
+            .account(
+                ctx.accounts.lot_balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .plaintext_bool(auction.winner.is_some())
+            .plaintext_u64(auction.clearing_price)
+            .plaintext_u64(auction.lot_amount)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SettleAuctionCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.auction.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.bid_balance.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.lot_balance.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Callback for the seller's settlement
+    ///
+    /// Both balances are always written back; the circuit leaves them
+    /// unchanged if the credit would overflow, in which case the seller may
+    /// settle again.
+    #[arcium_callback(encrypted_ix = "settle_auction")]
+    pub fn settle_auction_callback(
+        ctx: Context<SettleAuctionCallback>,
+        output: SignedComputationOutputs<SettleAuctionOutput>,
+    ) -> Result<()> {
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.auction.unlock();
+            ctx.accounts.bid_balance.unlock();
+            ctx.accounts.lot_balance.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(SettleAuctionOutput { field_0 }) => {
+                // field_0.field_0 = updated bid-mint balance (Enc<Mxe, u64>)
+                // field_0.field_1 = updated lot-mint balance (Enc<Mxe, u64>)
+                // field_0.field_2 = whether the payout was applied (revealed bool)
+                let new_bid_balance = field_0.field_0;
+                let new_lot_balance = field_0.field_1;
+                let settled = field_0.field_2;
+
+                ctx.accounts
+                    .bid_balance
+                    .set_balance(new_bid_balance.ciphertexts[0], new_bid_balance.nonce);
+                ctx.accounts
+                    .lot_balance
+                    .set_balance(new_lot_balance.ciphertexts[0], new_lot_balance.nonce);
+
+                let auction_key = ctx.accounts.auction.key();
+                let auction = &mut ctx.accounts.auction;
+                auction.seller_settled = settled;
+                auction.unlock();
+
+                emit!(SealedAuctionSettledEvent {
+                    auction: auction_key,
+                    seller: auction.seller,
+                    sold: auction.winner.is_some(),
+                    settled,
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // SELECTIVE DISCLOSURE
+    // =========================================================================
+
+    /// Disclose the payer's balance to an auditor without making it public
+    ///
+    /// Queues the `disclose_balance` circuit, which re-encrypts the balance to
+    /// `auditor_encryption_key`. The `BalanceDisclosure` it creates records
+    /// who disclosed which balance to whom and, once the callback lands,
+    /// holds the ciphertext for the auditor. The balance is locked until then,
+    /// so the disclosed value is the one at the recorded `sequence`.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique offset for this computation
+    /// * `disclosure_id` - Caller-chosen id, unique per owner
+    /// * `auditor` - Wallet of the party the balance is disclosed to
+    /// * `auditor_encryption_key` - X25519 public key the balance is encrypted to
+    pub fn disclose_balance(
+        ctx: Context<DiscloseBalance>,
+        computation_offset: u64,
+        disclosure_id: u64,
+        auditor: Pubkey,
+        auditor_encryption_key: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.computation_request.queue(
+            ctx.accounts.payer.key(),
+            CircuitKind::DiscloseBalance,
+            computation_offset,
+            &[ctx.accounts.balance_account.key(), ctx.accounts.disclosure.key()],
+            ctx.bumps.computation_request,
+        )?;
+        ctx.accounts.balance_account.lock(computation_offset)?;
+
+        let balance = &ctx.accounts.balance_account;
+        let disclosure = &mut ctx.accounts.disclosure;
+        disclosure.owner = balance.owner;
+        disclosure.balance_account = balance.key();
+        disclosure.mint = balance.mint;
+        disclosure.sequence = balance.sequence;
+        disclosure.auditor = auditor;
+        disclosure.auditor_encryption_key = auditor_encryption_key;
+        disclosure.disclosure_id = disclosure_id;
+        disclosure.disclosed_slot = Clock::get()?.slot;
+        disclosure.bump = ctx.bumps.disclosure;
+        disclosure.pending_computation = Some(computation_offset);
+
+        let args = ArgBuilder::new()
+            .plaintext_u128(balance.nonce)
+            .account(
+                balance.key(),
+                ENCRYPTED_BALANCE_OFFSET,
+                ENCRYPTED_BALANCE_LEN,
+            )
+            .x25519_pubkey(auditor_encryption_key)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![DiscloseBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.computation_request.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.balance_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.disclosure.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payer.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "disclose_balance")]
+    pub fn disclose_balance_callback(
+        ctx: Context<DiscloseBalanceCallback>,
+        output: SignedComputationOutputs<DiscloseBalanceOutput>,
+    ) -> Result<()> {
+        // Nothing was disclosed; close the record so it does not claim otherwise
+        if matches!(output, SignedComputationOutputs::Failure) {
+            ctx.accounts.balance_account.unlock();
+            record_abort(&mut ctx.accounts.computation_request)?;
+            ctx.accounts
+                .disclosure
+                .close(ctx.accounts.owner.to_account_info())?;
+            return Ok(());
+        }
+
+        let verified = output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        );
+
+        match verified {
+            Ok(DiscloseBalanceOutput { field_0 }) => {
+                ctx.accounts.balance_account.unlock();
+
+                let disclosure_key = ctx.accounts.disclosure.key();
+                let disclosure = &mut ctx.accounts.disclosure;
+                disclosure.encrypted_balance = field_0.ciphertexts[0];
+                disclosure.nonce = field_0.nonce;
+                disclosure.is_initialized = true;
+                disclosure.pending_computation = None;
+
+                emit!(BalanceDisclosedEvent {
+                    disclosure: disclosure_key,
+                    owner: disclosure.owner,
+                    balance_account: disclosure.balance_account,
+                    auditor: disclosure.auditor,
+                    sequence: disclosure.sequence,
+                    encrypted_balance: disclosure.encrypted_balance,
+                    nonce: field_0.nonce.to_le_bytes(),
+                });
+            }
+            Err(_) => return Err(ErrorCode::SignatureVerificationFailed.into()),
+        }
+
+        ctx.accounts.computation_request.complete()?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // VIEWING KEYS
+    // =========================================================================
+
+    /// Register, replace or remove the viewing key on the payer's balance
+    ///
+    /// While a key is registered, transfer callbacks also emit the balance's
+    /// side of each transfer encrypted to it, so its holder can follow the
+    /// history without being able to spend. Rejected while the balance is
+    /// locked, so an in-flight spend's views match the key it was queued
+    /// with. Credits already in flight to the incoming balance are still
+    /// encrypted to the key they were queued with.
+    ///
+    /// # Arguments
+    /// * `viewing_key` - X25519 public key to encrypt views to, `None` to stop
+    pub fn set_viewing_key(
+        ctx: Context<SetViewingKey>,
+        viewing_key: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let balance_account = &mut ctx.accounts.balance_account;
+        require!(
+            balance_account.pending_computation.is_none(),
+            ErrorCode::BalanceLocked
+        );
+        balance_account.viewing_key = viewing_key;
+
+        emit!(ViewingKeyUpdatedEvent {
+            balance_account: balance_account.key(),
+            owner: balance_account.owner,
+            viewing_key,
+        });
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // RECOVERY
+    // =========================================================================
+
+    /// Cancel a computation the cluster never called back
+    ///
+    /// Anyone may call it once `ProgramConfig::computation_timeout_slots`
+    /// have passed since queueing, so accounts locked by someone else's
+    /// computation can always be freed. The request is marked `Expired`, so a late callback fails
+    /// instead of applying. Every account in
+    /// `ComputationRequest::locked_accounts` must be passed, writable and in
+    /// that order, as remaining accounts, and is released, including a
+    /// balance's incoming lock; one still waiting for its initial ciphertext
+    /// is closed to the requester. An escrowed deposit is refunded to
+    /// `refund_token_account`, owned by the requester.
+    pub fn reclaim_expired_computation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReclaimExpiredComputation<'info>>,
+    ) -> Result<()> {
+        let request = &mut ctx.accounts.computation_request;
+        let expires_slot = request
+            .queued_slot
+            .saturating_add(ctx.accounts.config.computation_timeout_slots);
+        require!(
+            Clock::get()?.slot >= expires_slot,
+            ErrorCode::ComputationNotExpired
+        );
+        request.expire()?;
+
+        require!(
+            ctx.remaining_accounts.len() == request.locked_accounts.len(),
+            ErrorCode::LockedAccountsMismatch
+        );
+        for (info, locked) in ctx.remaining_accounts.iter().zip(&request.locked_accounts) {
+            require_keys_eq!(info.key(), *locked, ErrorCode::LockedAccountsMismatch);
+
+            let offset = request.computation_offset;
+            let requester = ctx.accounts.requester.to_account_info();
+            let data = info.try_borrow_data()?;
+            let is_pool = data.starts_with(Pool::DISCRIMINATOR);
+            let is_order = data.starts_with(LimitOrder::DISCRIMINATOR);
+            let is_auction = data.starts_with(SealedAuction::DISCRIMINATOR);
+            let is_bid = data.starts_with(SealedBid::DISCRIMINATOR);
+            let is_disclosure = data.starts_with(BalanceDisclosure::DISCRIMINATOR);
+            let is_fee_record = data.starts_with(FeeRecord::DISCRIMINATOR);
+            drop(data);
+
+            if is_pool {
+                release_expired::<Pool>(info, offset, requester, ErrorCode::PoolNotLocked)?;
+            } else if is_order {
+                release_expired::<LimitOrder>(info, offset, requester, ErrorCode::OrderNotLocked)?;
+            } else if is_auction {
+                release_expired::<SealedAuction>(
+                    info,
+                    offset,
+                    requester,
+                    ErrorCode::AuctionNotLocked,
+                )?;
+            } else if is_bid {
+                release_expired::<SealedBid>(info, offset, requester, ErrorCode::BidNotLocked)?;
+            } else if is_disclosure {
+                release_expired::<BalanceDisclosure>(
+                    info,
+                    offset,
+                    requester,
+                    ErrorCode::DisclosureNotLocked,
+                )?;
+            } else if is_fee_record {
+                release_expired::<FeeRecord>(
+                    info,
+                    offset,
+                    requester,
+                    ErrorCode::FeeRecordNotLocked,
+                )?;
+            } else {
+                release_expired::<EncryptedBalanceAccount>(
+                    info,
+                    offset,
+                    requester,
+                    ErrorCode::BalanceNotLocked,
+                )?;
+            }
+        }
+
+        let refund = request.escrowed_amount;
+        if refund > 0 {
+            let (
+                Some(mint),
+                Some(vault),
+                Some(vault_bump),
+                Some(refund_token_account),
+                Some(token_program),
+            ) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.vault,
+                ctx.bumps.vault,
+                &ctx.accounts.refund_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(ErrorCode::MissingRefundAccounts.into());
+            };
+
+            let mint_key = mint.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[VAULT_SEED, mint_key.as_ref(), &[vault_bump]]];
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: refund_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                refund,
+                mint.decimals,
+            )?;
+        }
+
+        emit!(ComputationExpiredEvent {
+            requester: request.requester,
+            circuit: request.circuit,
+            computation_offset: request.computation_offset,
+            refunded: refund,
+        });
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Split a pubkey into little-endian (low, high) u128 limbs, the form circuits compare addresses in
+pub fn pubkey_to_limbs(pubkey: &Pubkey) -> (u128, u128) {
+    let bytes = pubkey.to_bytes();
+    let mut lo = [0u8; 16];
+    let mut hi = [0u8; 16];
+    lo.copy_from_slice(&bytes[..16]);
+    hi.copy_from_slice(&bytes[16..]);
+    (u128::from_le_bytes(lo), u128::from_le_bytes(hi))
+}
+
+/// `Treasury::fee_bps` for the mint whose treasury PDA is `treasury`
+///
+/// Mints without a treasury charge no fee.
+fn treasury_fee_bps(treasury: &AccountInfo) -> Result<u16> {
+    if treasury.owner != &crate::ID {
+        return Ok(0);
+    }
+    let treasury = Treasury::try_deserialize(&mut &treasury.try_borrow_data()?[..])?;
+    Ok(treasury.fee_bps)
+}
+
+/// Callback entry for a transfer's optional `FeeRecord`
+///
+/// Anchor reads an optional account passed as the program ID as `None`.
+fn fee_record_callback_account(fee_record: Option<Pubkey>) -> CallbackAccount {
+    match fee_record {
+        Some(pubkey) => CallbackAccount {
+            pubkey,
+            is_writable: true,
+        },
+        None => CallbackAccount {
+            pubkey: crate::ID,
+            is_writable: false,
+        },
+    }
+}
+
+/// Addresses of a batch's recipient balances, in slot order
+fn recipient_keys(
+    recipient_balance_0: &Account<EncryptedBalanceAccount>,
+    recipient_balance_1: &Account<EncryptedBalanceAccount>,
+    recipient_balance_2: &Account<EncryptedBalanceAccount>,
+    recipient_balance_3: &Account<EncryptedBalanceAccount>,
+) -> [Pubkey; BATCH_SIZE] {
+    [
+        recipient_balance_0.key(),
+        recipient_balance_1.key(),
+        recipient_balance_2.key(),
+        recipient_balance_3.key(),
+    ]
+}
+
+/// Addresses of a batch auction's orders, in slot order
+fn order_keys(
+    order_0: &Account<LimitOrder>,
+    order_1: &Account<LimitOrder>,
+    order_2: &Account<LimitOrder>,
+    order_3: &Account<LimitOrder>,
+) -> [Pubkey; AUCTION_SIZE] {
+    [order_0.key(), order_1.key(), order_2.key(), order_3.key()]
+}
+
+/// Release an account locked by an expired computation
+///
+/// An account still waiting for its initial ciphertext is closed to
+/// `requester` instead.
+fn release_expired<'info, T: ComputationLocked>(
+    info: &'info AccountInfo<'info>,
+    computation_offset: u64,
+    requester: AccountInfo<'info>,
+    not_locked: ErrorCode,
+) -> Result<()> {
+    let mut account = Account::<T>::try_from(info)?;
+    if !account.is_held_by(computation_offset) {
+        return Err(not_locked.into());
+    }
+
+    if account.is_initialized() {
+        account.release(computation_offset);
+        account.exit(&crate::ID)
+    } else {
+        account.close(requester)
+    }
+}
+
+/// Record a computation the cluster aborted and emit `ComputationAbortedEvent`
+fn record_abort(request: &mut ComputationRequest) -> Result<()> {
+    request.abort()?;
+
+    emit!(ComputationAbortedEvent {
+        requester: request.requester,
+        circuit: request.circuit,
+        computation_offset: request.computation_offset,
+    });
+
+    Ok(())
+}
+
+// =============================================================================
+// STATE
+// =============================================================================
+
+/// Per-user, per-mint balance held as an MXE-encrypted `u64`
+///
+/// Only the MXE can decrypt `encrypted_balance`; circuits read it through an
+/// account reference and callbacks write the updated ciphertext back.
+#[account]
+#[derive(InitSpace)]
+pub struct EncryptedBalanceAccount {
+    /// Encrypted u64 balance (`Enc<Mxe, u64>`), must stay first
+    pub encrypted_balance: [u8; 32],
+    /// Nonce for `encrypted_balance`
+    pub nonce: u128,
+    /// Encrypted u64 credited by incoming transfers and not yet claimed
+    /// (`Enc<Mxe, u64>`), must stay right after `nonce`
+    pub encrypted_incoming: [u8; 32],
+    /// Nonce for `encrypted_incoming`
+    pub incoming_nonce: u128,
+    /// Wallet that owns this balance
+    pub owner: Pubkey,
+    /// SPL mint this balance is denominated in
+    pub mint: Pubkey,
+    /// X25519 public key that incoming transfer receipts are encrypted to
+    pub encryption_pubkey: [u8; 32],
+    /// X25519 public key transfer views are encrypted to, if registered
+    pub viewing_key: Option<[u8; 32]>,
+    /// Set once the MXE has written the initial balance
+    pub is_initialized: bool,
+    /// Number of balance writes, encrypted spends are bound to the current value
+    pub sequence: u64,
+    /// Offset of the queued computation that will write this balance, if any
+    pub pending_computation: Option<u64>,
+    /// Offset of the queued computation that will write `encrypted_incoming`, if any
+    pub pending_incoming: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EncryptedBalanceAccount {
+    /// Key the transfer circuits encrypt this balance's views to
+    ///
+    /// Falls back to `encryption_pubkey` when no viewing key is registered;
+    /// callbacks only emit views when one is.
+    pub fn view_key(&self) -> [u8; 32] {
+        self.viewing_key.unwrap_or(self.encryption_pubkey)
+    }
+
+    /// Store a new balance ciphertext from a callback and bump `sequence`
+    ///
+    /// Also releases the pending-computation lock, if any.
+    pub fn set_balance(&mut self, encrypted_balance: [u8; 32], nonce: u128) {
+        self.encrypted_balance = encrypted_balance;
+        self.nonce = nonce;
+        self.sequence += 1;
+        self.unlock();
+    }
+
+    /// Reserve the balance for the computation queued at `computation_offset`
+    ///
+    /// Fails if another computation that writes the balance is still pending.
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::BalanceLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+
+    /// Store a new incoming-balance ciphertext from a callback
+    ///
+    /// Leaves `sequence` alone, so credits never invalidate a spend the owner
+    /// has already encrypted. Also releases the incoming lock, if any.
+    pub fn set_incoming(&mut self, encrypted_incoming: [u8; 32], nonce: u128) {
+        self.encrypted_incoming = encrypted_incoming;
+        self.incoming_nonce = nonce;
+        self.unlock_incoming();
+    }
+
+    /// Reserve the incoming balance for the computation queued at `computation_offset`
+    ///
+    /// Independent of `lock`: a pending credit does not block the owner's spends.
+    pub fn lock_incoming(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_incoming.is_none(), ErrorCode::IncomingLocked);
+        self.pending_incoming = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the incoming lock
+    pub fn unlock_incoming(&mut self) {
+        self.pending_incoming = None;
+    }
+
+    /// Whether the incoming lock is held by `request`'s computation
+    pub fn is_incoming_locked_by(&self, request: &ComputationRequest) -> bool {
+        self.pending_incoming == Some(request.computation_offset)
+    }
+}
+
+/// Global program settings, a single PDA at `["config"]`
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    /// Signer for admin-only instructions
+    pub admin: Pubkey,
+    /// Admin proposed by `propose_admin`, until they accept
+    pub pending_admin: Option<Pubkey>,
+    /// Rejects new computations while set, except ones that only pay users out
+    pub paused: bool,
+    /// Slots after queueing before anyone may reclaim a computation
+    pub computation_timeout_slots: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Per-mint protocol fee settings
+///
+/// Each fee-charging transfer writes its fee to a `FeeRecord`, which the
+/// authority sweeps into its own `EncryptedBalanceAccount` for the mint and
+/// withdraws like any other balance.
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Mint the fee is charged in
+    pub mint: Pubkey,
+    /// Wallet allowed to sweep fees into its encrypted balance
+    pub authority: Pubkey,
+    /// Fee in basis points of each private transfer amount
+    pub fee_bps: u16,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Protocol fee charged by one `private_transfer` or `batch_transfer`
+///
+/// Transfers write their fee here rather than to a shared treasury balance,
+/// so transfers of a mint never wait on one another. `sweep_fee` credits the
+/// fee to the treasury authority and closes the record.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeRecord {
+    /// Encrypted fee (`Enc<Mxe, u64>`), zero if the transfer was rejected; must stay first
+    pub encrypted_fee: [u8; 32],
+    /// Nonce for `encrypted_fee`
+    pub nonce: u128,
+    /// Mint the fee is charged in
+    pub mint: Pubkey,
+    /// Sender who paid the rent, refunded when the record is closed
+    pub payer: Pubkey,
+    /// Set once the MXE has written the fee
+    pub is_initialized: bool,
+    /// Offset of the queued computation that will write or sweep the fee, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FeeRecord {
+    /// Fill in a record created by the transfer queued at `computation_offset`
+    ///
+    /// The record stays locked until the transfer's callback writes the fee.
+    pub fn open(&mut self, mint: Pubkey, payer: Pubkey, computation_offset: u64, bump: u8) {
+        self.mint = mint;
+        self.payer = payer;
+        self.pending_computation = Some(computation_offset);
+        self.bump = bump;
+    }
+
+    /// Store the fee ciphertext from a transfer callback and release the lock
+    pub fn set_fee(&mut self, encrypted_fee: [u8; 32], nonce: u128) {
+        self.encrypted_fee = encrypted_fee;
+        self.nonce = nonce;
+        self.is_initialized = true;
+        self.unlock();
+    }
+
+    /// Reserve the record for the sweep queued at `computation_offset`
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::FeeRecordLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+}
+
+/// Oracle price for swapping `input_mint` into `output_mint`
+///
+/// Read by `validate_oracle_swap`, which passes `price` to the MXE as a
+/// plaintext input.
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    /// Mint debited by the swap
+    pub input_mint: Pubkey,
+    /// Mint credited by the swap
+    pub output_mint: Pubkey,
+    /// Wallet allowed to publish prices
+    pub authority: Pubkey,
+    /// Output base units per input base unit, scaled by `PRICE_SCALE`
+    pub price: u64,
+    /// Slot of the last `update_price`
+    pub updated_slot: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Constant-product pool for a pair of mints, with MXE-encrypted reserves
+///
+/// Reserves are only ever read and written by the MXE, so neither the pool's
+/// depth nor its price is public. `mint_a` sorts before `mint_b`.
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    /// Encrypted reserves (`Enc<Mxe, PoolReserves>`): `mint_a`, then `mint_b`; must stay first
+    pub encrypted_reserves: [[u8; 32]; 2],
+    /// Nonce for `encrypted_reserves`
+    pub nonce: u128,
+    /// First mint of the pair
+    pub mint_a: Pubkey,
+    /// Second mint of the pair
+    pub mint_b: Pubkey,
+    /// Swap fee in basis points of the input amount, kept in the reserves
+    pub fee_bps: u16,
+    /// Set once the MXE has written the initial reserves
+    pub is_initialized: bool,
+    /// Offset of the queued computation that will write the reserves, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Pool {
+    /// Store new reserve ciphertexts from a callback and release the lock
+    pub fn set_reserves(&mut self, encrypted_reserves: [[u8; 32]; 2], nonce: u128) {
+        self.encrypted_reserves = encrypted_reserves;
+        self.nonce = nonce;
+        self.unlock();
+    }
+
+    /// Reserve the pool for the computation queued at `computation_offset`
+    ///
+    /// Swaps against a pool are serialized: each one reads the reserves the
+    /// previous one wrote.
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::PoolLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+}
+
+/// Resting limit order whose side, price and remaining size are MXE-encrypted
+///
+/// Placed by `place_order`, which escrows its funds from the owner's
+/// balance, and updated by every `match_orders` it takes part in. Fills move
+/// value between the orders' escrow and proceeds; `settle_order` pays the
+/// proceeds out to the owner's balances. Only the pair, the owner and the
+/// matcher are public.
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    /// Encrypted order (`Enc<Mxe, OrderState>`): side, price, remaining size,
+    /// escrow and proceeds; must stay first
+    pub encrypted_state: [[u8; 32]; 5],
+    /// Nonce for `encrypted_state`
+    pub nonce: u128,
+    /// Wallet that placed the order
+    pub owner: Pubkey,
+    /// Mint being bought or sold
+    pub base_mint: Pubkey,
+    /// Mint the price is quoted in
+    pub quote_mint: Pubkey,
+    /// Owner-chosen id, part of the PDA seeds
+    pub order_id: u64,
+    /// X25519 public key that fill notices are encrypted to
+    pub encryption_pubkey: [u8; 32],
+    /// Wallet allowed to match the order
+    pub matcher: Pubkey,
+    /// Set once the MXE has written the order
+    pub is_initialized: bool,
+    /// Set while a `settle_order` that will close the order is pending
+    pub closing: bool,
+    /// Offset of the queued computation that will write the order, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LimitOrder {
+    /// Store a new order ciphertext from a callback and release the lock
+    pub fn set_state(&mut self, encrypted_state: [[u8; 32]; 5], nonce: u128) {
+        self.encrypted_state = encrypted_state;
+        self.nonce = nonce;
+        self.unlock();
+    }
+
+    /// Reserve the order for the computation queued at `computation_offset`
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::OrderLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+}
+
+/// Sealed-bid auction whose bids are only ever seen by the MXE
+///
+/// Opened by `create_auction`, which escrows the lot out of the seller's
+/// encrypted balance; each `submit_bid` escrows one bid and folds it into the
+/// encrypted standing, and once `end_slot` is reached `resolve_auction`
+/// reveals just the winner and the clearing price. Bidders are public, their
+/// amounts are not.
+#[account]
+#[derive(InitSpace)]
+pub struct SealedAuction {
+    /// Encrypted standing (`Enc<Mxe, SealedBids>`): highest, second-highest, winner; must stay first
+    pub encrypted_bids: [[u8; 32]; 3],
+    /// Nonce for `encrypted_bids`
+    pub nonce: u128,
+    /// Wallet that opened the auction and receives its proceeds
+    pub seller: Pubkey,
+    /// Mint being sold
+    pub lot_mint: Pubkey,
+    /// Mint bids are denominated in
+    pub bid_mint: Pubkey,
+    /// Seller-chosen id, part of the PDA seeds
+    pub auction_id: u64,
+    /// Whether the winner pays the second-highest bid (Vickrey)
+    pub second_price: bool,
+    /// Amount of `lot_mint` escrowed from the seller
+    pub lot_amount: u64,
+    /// First slot at which bids are refused and the auction can be resolved
+    pub end_slot: u64,
+    /// Lowest clearing price in `bid_mint`; smaller bids never win
+    pub reserve_price: u64,
+    /// Bidders in submission order; a bid's index is its position here
+    #[max_len(MAX_AUCTION_BIDS)]
+    pub bidders: Vec<Pubkey>,
+    /// Set once `resolve_auction` has landed
+    pub resolved: bool,
+    /// Winning bidder, `None` until resolved or if no positive bid was placed
+    pub winner: Option<Pubkey>,
+    /// Price the winner pays in `bid_mint`, set when resolved
+    pub clearing_price: u64,
+    /// Set once `settle_auction` has paid the seller
+    pub seller_settled: bool,
+    /// Set once the MXE has written the initial standing
+    pub is_initialized: bool,
+    /// Offset of the queued computation that will write the standing, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SealedAuction {
+    /// Store a new standing ciphertext from a callback and release the lock
+    pub fn set_bids(&mut self, encrypted_bids: [[u8; 32]; 3], nonce: u128) {
+        self.encrypted_bids = encrypted_bids;
+        self.nonce = nonce;
+        self.unlock();
+    }
+
+    /// Reserve the auction for the computation queued at `computation_offset`
+    ///
+    /// Bids are serialized: each one reads the standing the previous one wrote.
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::AuctionLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+}
+
+/// Record of a balance disclosed to an auditor by `disclose_balance`
+///
+/// Who disclosed (`owner`), what (`balance_account` at `sequence`) and to whom
+/// (`auditor`, `auditor_encryption_key`) are public. The balance itself is
+/// only readable with the auditor's X25519 secret.
+#[account]
+#[derive(InitSpace)]
+pub struct BalanceDisclosure {
+    /// Balance encrypted to the auditor (`Enc<Shared, u64>`)
+    pub encrypted_balance: [u8; 32],
+    /// Nonce for `encrypted_balance`
+    pub nonce: u128,
+    /// Wallet that owns the disclosed balance
+    pub owner: Pubkey,
+    /// `EncryptedBalanceAccount` that was disclosed
+    pub balance_account: Pubkey,
+    /// Mint of the disclosed balance
+    pub mint: Pubkey,
+    /// `EncryptedBalanceAccount::sequence` of the disclosed value
+    pub sequence: u64,
+    /// Wallet of the party the balance was disclosed to
+    pub auditor: Pubkey,
+    /// X25519 public key `encrypted_balance` is encrypted to
+    pub auditor_encryption_key: [u8; 32],
+    /// Owner-chosen id, part of the PDA seeds
+    pub disclosure_id: u64,
+    /// Slot the disclosure was requested in
+    pub disclosed_slot: u64,
+    /// Set once the MXE has written `encrypted_balance`
+    pub is_initialized: bool,
+    /// Offset of the queued computation that will write the disclosure, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A bidder's escrowed bid on a `SealedAuction`
+///
+/// Created by `submit_bid`, which moves the bid out of the bidder's balance
+/// into `encrypted_escrow`. `settle_bid` pays it out once the auction is
+/// resolved and closes the record.
+#[account]
+#[derive(InitSpace)]
+pub struct SealedBid {
+    /// Encrypted escrow (`Enc<Mxe, u64>`), zero if the balance did not cover the bid; must stay first
+    pub encrypted_escrow: [u8; 32],
+    /// Nonce for `encrypted_escrow`
+    pub nonce: u128,
+    /// Auction the bid was placed on
+    pub auction: Pubkey,
+    /// Wallet that placed the bid and paid the rent
+    pub bidder: Pubkey,
+    /// Position of the bid in `SealedAuction::bidders`
+    pub bid_index: u8,
+    /// Set once the MXE has written the escrow
+    pub is_initialized: bool,
+    /// Offset of the queued computation that will write or settle the escrow, if any
+    pub pending_computation: Option<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SealedBid {
+    /// Fill in a bid created by the `submit_bid` queued at `computation_offset`
+    ///
+    /// The bid stays locked until the callback writes the escrow.
+    pub fn open(
+        &mut self,
+        auction: Pubkey,
+        bidder: Pubkey,
+        bid_index: u8,
+        computation_offset: u64,
+        bump: u8,
+    ) {
+        self.auction = auction;
+        self.bidder = bidder;
+        self.bid_index = bid_index;
+        self.pending_computation = Some(computation_offset);
+        self.bump = bump;
+    }
+
+    /// Store the escrow ciphertext from a `submit_bid` callback and release the lock
+    pub fn set_escrow(&mut self, encrypted_escrow: [u8; 32], nonce: u128) {
+        self.encrypted_escrow = encrypted_escrow;
+        self.nonce = nonce;
+        self.is_initialized = true;
+        self.unlock();
+    }
+
+    /// Reserve the bid for the settlement queued at `computation_offset`
+    pub fn lock(&mut self, computation_offset: u64) -> Result<()> {
+        require!(self.pending_computation.is_none(), ErrorCode::BidLocked);
+        self.pending_computation = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Release the pending-computation lock
+    pub fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+}
+
+/// Circuit a `ComputationRequest` was queued for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CircuitKind {
+    CreateBalance,
+    Deposit,
+    Withdraw,
+    PrivateTransfer,
+    CheckBalance,
+    ValidateSwap,
+    BatchTransfer,
+    ValidateOracleSwap,
+    InitPool,
+    AddLiquidity,
+    SwapCpmm,
+    PlaceOrder,
+    MatchOrders,
+    BatchAuction,
+    InitAuction,
+    SubmitBid,
+    ResolveAuction,
+    DiscloseBalance,
+    ClaimIncoming,
+    SweepFee,
+    RemoveLiquidity,
+    SettleOrder,
+    SettleBid,
+    SettleAuction,
+}
+
+/// Lifecycle of a `ComputationRequest`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ComputationStatus {
+    /// Queued with the MXE, callback not yet received
+    Queued,
+    /// Callback verified and applied
+    Completed,
+    /// The computation failed and will not complete
+    Aborted,
+    /// The timeout passed without a callback and the request was reclaimed
+    Expired,
+}
+
+/// On-chain record of one queued computation, keyed by `computation_offset`
+///
+/// Created when the computation is queued and updated by its callback, so
+/// wallets can poll the outcome instead of scraping event logs.
+#[account]
+#[derive(InitSpace)]
+pub struct ComputationRequest {
+    /// Wallet that queued the computation
+    pub requester: Pubkey,
+    /// Circuit the computation runs
+    pub circuit: CircuitKind,
+    /// Arcium computation offset, also the PDA seed
+    pub computation_offset: u64,
+    /// Slot the computation was queued in
+    pub queued_slot: u64,
+    /// Mint of `escrowed_amount`
+    pub escrow_mint: Pubkey,
+    /// Tokens moved into the vault when queued, refunded if the computation aborts
+    pub escrowed_amount: u64,
+    /// Accounts the computation locked, in the order `reclaim_expired_computation` takes them
+    #[max_len(MAX_LOCKED_ACCOUNTS)]
+    pub locked_accounts: Vec<Pubkey>,
+    /// Current status
+    pub status: ComputationStatus,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ComputationRequest {
+    /// Record a newly queued computation and the accounts it locks
+    pub fn queue(
+        &mut self,
+        requester: Pubkey,
+        circuit: CircuitKind,
+        computation_offset: u64,
+        locked_accounts: &[Pubkey],
+        bump: u8,
+    ) -> Result<()> {
+        self.requester = requester;
+        self.circuit = circuit;
+        self.computation_offset = computation_offset;
+        self.queued_slot = Clock::get()?.slot;
+        self.escrow_mint = Pubkey::default();
+        self.escrowed_amount = 0;
+        self.locked_accounts = locked_accounts.to_vec();
+        self.status = ComputationStatus::Queued;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Mark the computation completed, once its callback has been applied
+    pub fn complete(&mut self) -> Result<()> {
+        require!(
+            self.status == ComputationStatus::Queued,
+            ErrorCode::ComputationNotQueued
+        );
+        self.status = ComputationStatus::Completed;
+        Ok(())
+    }
+
+    /// Mark the computation aborted, it will never complete
+    pub fn abort(&mut self) -> Result<()> {
+        require!(
+            self.status == ComputationStatus::Queued,
+            ErrorCode::ComputationNotQueued
+        );
+        self.status = ComputationStatus::Aborted;
+        Ok(())
+    }
+
+    /// Mark the computation expired, its callback will be rejected
+    pub fn expire(&mut self) -> Result<()> {
+        require!(
+            self.status == ComputationStatus::Queued,
+            ErrorCode::ComputationNotQueued
+        );
+        self.status = ComputationStatus::Expired;
+        Ok(())
+    }
+
+    /// Record tokens moved into the vault for this computation
+    pub fn escrow(&mut self, mint: Pubkey, amount: u64) {
+        self.escrow_mint = mint;
+        self.escrowed_amount = amount;
+    }
+}
+
+/// Account whose encrypted state is written by one computation at a time
+trait ComputationLocked: AccountSerialize + AccountDeserialize + Owner + Clone {
+    /// Offset of the computation holding the lock, if any
+    fn pending_computation(&self) -> Option<u64>;
+    /// Whether the MXE has written the initial state
+    fn is_initialized(&self) -> bool;
+    /// Release the lock
+    fn unlock(&mut self);
+
+    /// Whether the lock is held by `request`'s computation
+    fn is_locked_by(&self, request: &ComputationRequest) -> bool {
+        self.pending_computation() == Some(request.computation_offset)
+    }
+
+    /// Whether any lock on the account is held by the computation at `computation_offset`
+    fn is_held_by(&self, computation_offset: u64) -> bool {
+        self.pending_computation() == Some(computation_offset)
+    }
+
+    /// Release every lock held by the computation at `computation_offset`
+    fn release(&mut self, _computation_offset: u64) {
+        self.unlock();
+    }
+}
+
+macro_rules! impl_computation_locked {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl ComputationLocked for $account {
+                fn pending_computation(&self) -> Option<u64> {
+                    self.pending_computation
+                }
+
+                fn is_initialized(&self) -> bool {
+                    self.is_initialized
+                }
+
+                fn unlock(&mut self) {
+                    self.pending_computation = None;
+                }
+            }
+        )*
+    };
+}
+
+impl ComputationLocked for EncryptedBalanceAccount {
+    fn pending_computation(&self) -> Option<u64> {
+        self.pending_computation
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+
+    fn unlock(&mut self) {
+        self.pending_computation = None;
+    }
+
+    fn is_held_by(&self, computation_offset: u64) -> bool {
+        self.pending_computation == Some(computation_offset)
+            || self.pending_incoming == Some(computation_offset)
+    }
+
+    fn release(&mut self, computation_offset: u64) {
+        if self.pending_computation == Some(computation_offset) {
+            self.pending_computation = None;
+        }
+        if self.pending_incoming == Some(computation_offset) {
+            self.pending_incoming = None;
+        }
+    }
+}
+
+impl_computation_locked!(
+    Pool,
+    LimitOrder,
+    SealedAuction,
+    SealedBid,
+    BalanceDisclosure,
+    FeeRecord,
+);
+
+// =============================================================================
+// ACCOUNT STRUCTURES
+// =============================================================================
+
+// Create Balance Accounts
+#[queue_computation_accounts("create_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CreateBalance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EncryptedBalanceAccount::INIT_SPACE,
+        seeds = [BALANCE_SEED, mint.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREATE_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("create_balance")]
+#[derive(Accounts)]
+pub struct CreateBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREATE_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the balance account rent if the computation aborts
+    #[account(mut, address = balance_account.owner)]
+    pub owner: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("create_balance", payer)]
+#[derive(Accounts)]
+pub struct InitCreateBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Config Accounts
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::SipArciumTransfer>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetComputationTimeout<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(pending_admin.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
+
+// Vault Accounts
+#[derive(Accounts)]
+pub struct InitVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Treasury Accounts
+#[derive(Accounts)]
+pub struct InitTreasury<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [TREASURY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryFee<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+// Deposit Accounts
+#[queue_computation_accounts("deposit", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, mint.key().as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("deposit")]
+#[derive(Accounts)]
+pub struct DepositCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        address = computation_request.escrow_mint @ ErrorCode::MintMismatch,
+        constraint = mint.key() == balance_account.mint @ ErrorCode::MintMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// Receives the deposit back if the computation aborts
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = payer_token_account.owner == computation_request.requester
+            @ ErrorCode::Unauthorized,
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[init_computation_definition_accounts("deposit", payer)]
+#[derive(Accounts)]
+pub struct InitDepositCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Withdraw Accounts
+#[queue_computation_accounts("withdraw", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, mint.key().as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub destination: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_WITHDRAW))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("withdraw")]
+#[derive(Accounts)]
+pub struct WithdrawCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_WITHDRAW))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(address = balance_account.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub destination: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[init_computation_definition_accounts("withdraw", payer)]
+#[derive(Accounts)]
+pub struct InitWithdrawCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Private Transfer Accounts
+#[queue_computation_accounts("private_transfer", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PrivateTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, sender_balance.mint.as_ref(), payer.key().as_ref()],
+        bump = sender_balance.bump,
+        constraint = sender_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub sender_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [
+            BALANCE_SEED,
+            recipient_balance.mint.as_ref(),
+            recipient_balance.owner.as_ref(),
+        ],
+        bump = recipient_balance.bump,
+        constraint = recipient_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = recipient_balance.mint == sender_balance.mint @ ErrorCode::MintMismatch,
+        constraint = recipient_balance.key() != sender_balance.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub recipient_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(seeds = [TREASURY_SEED, sender_balance.mint.as_ref()], bump)]
+    /// CHECK: the mint's `Treasury` PDA, read by `treasury_fee_bps`; may not exist
+    pub treasury: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeRecord::INIT_SPACE,
+        seeds = [FEE_RECORD_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub fee_record: Option<Account<'info, FeeRecord>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("private_transfer")]
+#[derive(Accounts)]
+pub struct PrivateTransferCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = sender_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub sender_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = recipient_balance.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub recipient_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = fee_record.is_locked_by(&computation_request) @ ErrorCode::FeeRecordNotLocked,
+    )]
+    pub fee_record: Option<Account<'info, FeeRecord>>,
+    /// Receives the fee record rent if the computation aborts
+    #[account(mut, address = sender_balance.owner)]
+    pub sender: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("private_transfer", payer)]
+#[derive(Accounts)]
+pub struct InitPrivateTransferCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Batch Transfer Accounts
+#[queue_computation_accounts("batch_transfer", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct BatchTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, sender_balance.mint.as_ref(), payer.key().as_ref()],
+        bump = sender_balance.bump,
+        constraint = sender_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub sender_balance: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            BALANCE_SEED,
+            recipient_balance_0.mint.as_ref(),
+            recipient_balance_0.owner.as_ref(),
+        ],
+        bump = recipient_balance_0.bump,
+        constraint = recipient_balance_0.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = recipient_balance_0.mint == sender_balance.mint @ ErrorCode::MintMismatch,
+        constraint = recipient_balance_0.key() != sender_balance.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub recipient_balance_0: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            BALANCE_SEED,
+            recipient_balance_1.mint.as_ref(),
+            recipient_balance_1.owner.as_ref(),
+        ],
+        bump = recipient_balance_1.bump,
+        constraint = recipient_balance_1.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = recipient_balance_1.mint == sender_balance.mint @ ErrorCode::MintMismatch,
+        constraint = recipient_balance_1.key() != sender_balance.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub recipient_balance_1: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            BALANCE_SEED,
+            recipient_balance_2.mint.as_ref(),
+            recipient_balance_2.owner.as_ref(),
+        ],
+        bump = recipient_balance_2.bump,
+        constraint = recipient_balance_2.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = recipient_balance_2.mint == sender_balance.mint @ ErrorCode::MintMismatch,
+        constraint = recipient_balance_2.key() != sender_balance.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub recipient_balance_2: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            BALANCE_SEED,
+            recipient_balance_3.mint.as_ref(),
+            recipient_balance_3.owner.as_ref(),
+        ],
+        bump = recipient_balance_3.bump,
+        constraint = recipient_balance_3.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = recipient_balance_3.mint == sender_balance.mint @ ErrorCode::MintMismatch,
+        constraint = recipient_balance_3.key() != sender_balance.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub recipient_balance_3: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(seeds = [TREASURY_SEED, sender_balance.mint.as_ref()], bump)]
+    /// CHECK: the mint's `Treasury` PDA, read by `treasury_fee_bps`; may not exist
+    pub treasury: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeRecord::INIT_SPACE,
+        seeds = [FEE_RECORD_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub fee_record: Option<Box<Account<'info, FeeRecord>>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+impl<'info> BatchTransfer<'info> {
+    /// Recipient balances in slot order
+    fn recipient_balances(&self) -> [&EncryptedBalanceAccount; BATCH_SIZE] {
+        [
+            &self.recipient_balance_0,
+            &self.recipient_balance_1,
+            &self.recipient_balance_2,
+            &self.recipient_balance_3,
+        ]
+    }
+
+    /// Recipient balance addresses in slot order
+    fn recipient_keys(&self) -> [Pubkey; BATCH_SIZE] {
+        recipient_keys(
+            &self.recipient_balance_0,
+            &self.recipient_balance_1,
+            &self.recipient_balance_2,
+            &self.recipient_balance_3,
+        )
+    }
+}
+
+#[callback_accounts("batch_transfer")]
+#[derive(Accounts)]
+pub struct BatchTransferCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = sender_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub sender_balance: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        constraint = recipient_balance_0.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub recipient_balance_0: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        constraint = recipient_balance_1.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub recipient_balance_1: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        constraint = recipient_balance_2.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub recipient_balance_2: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        constraint = recipient_balance_3.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub recipient_balance_3: Box<Account<'info, EncryptedBalanceAccount>>,
+    #[account(
+        mut,
+        constraint = fee_record.is_locked_by(&computation_request) @ ErrorCode::FeeRecordNotLocked,
+    )]
+    pub fee_record: Option<Box<Account<'info, FeeRecord>>>,
+    /// Receives the fee record rent if the computation aborts
+    #[account(mut, address = sender_balance.owner)]
+    pub sender: SystemAccount<'info>,
+}
+
+impl<'info> BatchTransferCallback<'info> {
+    /// Recipient balances in slot order
+    fn recipient_balances(&self) -> [&EncryptedBalanceAccount; BATCH_SIZE] {
+        [
+            &self.recipient_balance_0,
+            &self.recipient_balance_1,
+            &self.recipient_balance_2,
+            &self.recipient_balance_3,
+        ]
+    }
+
+    /// Recipient balances in slot order, for writing
+    fn recipient_balances_mut(&mut self) -> [&mut EncryptedBalanceAccount; BATCH_SIZE] {
+        [
+            &mut self.recipient_balance_0,
+            &mut self.recipient_balance_1,
+            &mut self.recipient_balance_2,
+            &mut self.recipient_balance_3,
+        ]
+    }
+
+    /// Recipient balance addresses in slot order
+    fn recipient_keys(&self) -> [Pubkey; BATCH_SIZE] {
+        recipient_keys(
+            &self.recipient_balance_0,
+            &self.recipient_balance_1,
+            &self.recipient_balance_2,
+            &self.recipient_balance_3,
+        )
+    }
+}
+
+#[init_computation_definition_accounts("batch_transfer", payer)]
+#[derive(Accounts)]
+pub struct InitBatchTransferCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Sweep Fee Accounts
+#[queue_computation_accounts("sweep_fee", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SweepFee<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [TREASURY_SEED, fee_record.mint.as_ref()],
+        bump = treasury.bump,
+        constraint = treasury.authority == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, fee_record.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = fee_record.is_initialized @ ErrorCode::FeeRecordNotInitialized,
+    )]
+    pub fee_record: Account<'info, FeeRecord>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWEEP_FEE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("sweep_fee")]
+#[derive(Accounts)]
+pub struct SweepFeeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWEEP_FEE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = fee_record.is_locked_by(&computation_request) @ ErrorCode::FeeRecordNotLocked,
+    )]
+    pub fee_record: Account<'info, FeeRecord>,
+    /// Receives the fee record rent once the fee is swept
+    #[account(mut, address = fee_record.payer)]
+    pub fee_payer: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("sweep_fee", payer)]
+#[derive(Accounts)]
+pub struct InitSweepFeeCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Claim Incoming Accounts
+#[queue_computation_accounts("claim_incoming", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimIncoming<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_INCOMING))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("claim_incoming")]
+#[derive(Accounts)]
+pub struct ClaimIncomingCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_INCOMING))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+        constraint = balance_account.is_incoming_locked_by(&computation_request)
+            @ ErrorCode::IncomingNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+}
+
+#[init_computation_definition_accounts("claim_incoming", payer)]
+#[derive(Accounts)]
+pub struct InitClaimIncomingCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Check Balance Accounts
+#[queue_computation_accounts("check_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CheckBalance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("check_balance")]
+#[derive(Accounts)]
+pub struct CheckBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+}
+
+#[init_computation_definition_accounts("check_balance", payer)]
+#[derive(Accounts)]
+pub struct InitCheckBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Validate Swap Accounts
+#[queue_computation_accounts("validate_swap", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ValidateSwap<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        seeds = [BALANCE_SEED, output_balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = output_balance_account.bump,
+        constraint = output_balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = output_balance_account.mint != balance_account.mint @ ErrorCode::SwapMintsMatch,
+    )]
+    pub output_balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_SWAP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("validate_swap")]
+#[derive(Accounts)]
+pub struct ValidateSwapCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_SWAP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+}
+
+#[init_computation_definition_accounts("validate_swap", payer)]
+#[derive(Accounts)]
+pub struct InitValidateSwapCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Price Feed Accounts
+#[derive(Accounts)]
+pub struct InitPriceFeed<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub input_mint: Account<'info, Mint>,
+    #[account(constraint = output_mint.key() != input_mint.key() @ ErrorCode::SwapMintsMatch)]
+    pub output_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED_SEED, input_mint.key().as_ref(), output_mint.key().as_ref()],
+        bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PRICE_FEED_SEED,
+            price_feed.input_mint.as_ref(),
+            price_feed.output_mint.as_ref(),
+        ],
+        bump = price_feed.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+// Oracle Swap Accounts
+#[queue_computation_accounts("validate_oracle_swap", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ValidateOracleSwap<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        seeds = [BALANCE_SEED, output_balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = output_balance_account.bump,
+        constraint = output_balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+        constraint = output_balance_account.mint != balance_account.mint @ ErrorCode::SwapMintsMatch,
+    )]
+    pub output_balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        seeds = [
+            PRICE_FEED_SEED,
+            balance_account.mint.as_ref(),
+            output_balance_account.mint.as_ref(),
+        ],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_ORACLE_SWAP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("validate_oracle_swap")]
+#[derive(Accounts)]
+pub struct ValidateOracleSwapCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_ORACLE_SWAP))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+}
+
+#[init_computation_definition_accounts("validate_oracle_swap", payer)]
+#[derive(Accounts)]
+pub struct InitValidateOracleSwapCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pool Accounts
+#[queue_computation_accounts("init_pool", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub mint_a: Account<'info, Mint>,
+    #[account(constraint = mint_a.key() < mint_b.key() @ ErrorCode::InvalidPoolMints)]
+    pub mint_b: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [POOL_SEED, mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_POOL))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("init_pool")]
+#[derive(Accounts)]
+pub struct InitPoolCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_POOL))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = pool.is_locked_by(&computation_request) @ ErrorCode::PoolNotLocked,
+    )]
+    pub pool: Account<'info, Pool>,
+    /// Receives the pool rent if the computation aborts
+    #[account(mut, address = computation_request.requester)]
+    pub requester: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("init_pool", payer)]
+#[derive(Accounts)]
+pub struct InitInitPoolCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("add_liquidity", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump,
+        constraint = pool.is_initialized @ ErrorCode::PoolNotInitialized,
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_a.as_ref(), payer.key().as_ref()],
+        bump = provider_balance_a.bump,
+        constraint = provider_balance_a.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub provider_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_b.as_ref(), payer.key().as_ref()],
+        bump = provider_balance_b.bump,
+        constraint = provider_balance_b.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub provider_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_LIQUIDITY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("add_liquidity")]
+#[derive(Accounts)]
+pub struct AddLiquidityCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_LIQUIDITY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = provider_balance_a.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub provider_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = provider_balance_b.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub provider_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = pool.is_locked_by(&computation_request) @ ErrorCode::PoolNotLocked,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[init_computation_definition_accounts("add_liquidity", payer)]
+#[derive(Accounts)]
+pub struct InitAddLiquidityCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("remove_liquidity", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump,
+        constraint = pool.is_initialized @ ErrorCode::PoolNotInitialized,
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_a.as_ref(), payer.key().as_ref()],
+        bump = provider_balance_a.bump,
+        constraint = provider_balance_a.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub provider_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_b.as_ref(), payer.key().as_ref()],
+        bump = provider_balance_b.bump,
+        constraint = provider_balance_b.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub provider_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REMOVE_LIQUIDITY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("remove_liquidity")]
+#[derive(Accounts)]
+pub struct RemoveLiquidityCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REMOVE_LIQUIDITY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = provider_balance_a.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub provider_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = provider_balance_b.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub provider_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = pool.is_locked_by(&computation_request) @ ErrorCode::PoolNotLocked,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[init_computation_definition_accounts("remove_liquidity", payer)]
+#[derive(Accounts)]
+pub struct InitRemoveLiquidityCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("swap_cpmm", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SwapCpmm<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref()],
+        bump = pool.bump,
+        constraint = pool.is_initialized @ ErrorCode::PoolNotInitialized,
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_a.as_ref(), payer.key().as_ref()],
+        bump = trader_balance_a.bump,
+        constraint = trader_balance_a.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub trader_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, pool.mint_b.as_ref(), payer.key().as_ref()],
+        bump = trader_balance_b.bump,
+        constraint = trader_balance_b.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub trader_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWAP_CPMM))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("swap_cpmm")]
+#[derive(Accounts)]
+pub struct SwapCpmmCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWAP_CPMM))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = trader_balance_a.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub trader_balance_a: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = trader_balance_b.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub trader_balance_b: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = pool.is_locked_by(&computation_request) @ ErrorCode::PoolNotLocked,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[init_computation_definition_accounts("swap_cpmm", payer)]
+#[derive(Accounts)]
+pub struct InitSwapCpmmCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// Limit Order Accounts
+#[queue_computation_accounts("place_order", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, order_id: u64)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub base_mint: Account<'info, Mint>,
+    #[account(constraint = quote_mint.key() != base_mint.key() @ ErrorCode::OrderMintsMatch)]
+    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LimitOrder::INIT_SPACE,
+        seeds = [ORDER_SEED, payer.key().as_ref(), order_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub order: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, base_mint.key().as_ref(), payer.key().as_ref()],
+        bump = base_balance.bump,
+        constraint = base_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub base_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, quote_mint.key().as_ref(), payer.key().as_ref()],
+        bump = quote_balance.bump,
+        constraint = quote_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub quote_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLACE_ORDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("place_order")]
+#[derive(Accounts)]
+pub struct PlaceOrderCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PLACE_ORDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = order.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        constraint = base_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub base_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = quote_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub quote_balance: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the order rent if the computation aborts
+    #[account(mut, address = order.owner)]
+    pub owner: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("place_order", payer)]
+#[derive(Accounts)]
+pub struct InitPlaceOrderCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("settle_order", owner)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SettleOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, owner.key().as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump,
+        constraint = order.is_initialized @ ErrorCode::OrderNotInitialized,
+    )]
+    pub order: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, order.base_mint.as_ref(), owner.key().as_ref()],
+        bump = base_balance.bump,
+        constraint = base_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub base_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, order.quote_mint.as_ref(), owner.key().as_ref()],
+        bump = quote_balance.bump,
+        constraint = quote_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub quote_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = owner,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_ORDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("settle_order")]
+#[derive(Accounts)]
+pub struct SettleOrderCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_ORDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = order.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        constraint = base_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub base_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = quote_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub quote_balance: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the order rent when a closing order settles
+    #[account(mut, address = order.owner)]
+    pub owner: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("settle_order", payer)]
+#[derive(Accounts)]
+pub struct InitSettleOrderCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("match_orders", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct MatchOrders<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_a.owner.as_ref(), order_a.order_id.to_le_bytes().as_ref()],
+        bump = order_a.bump,
+        constraint = order_a.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_a.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+    )]
+    pub order_a: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_b.owner.as_ref(), order_b.order_id.to_le_bytes().as_ref()],
+        bump = order_b.bump,
+        constraint = order_b.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_b.key() != order_a.key() @ ErrorCode::SelfMatch,
+        constraint = order_b.base_mint == order_a.base_mint @ ErrorCode::MintMismatch,
+        constraint = order_b.quote_mint == order_a.quote_mint @ ErrorCode::MintMismatch,
+        constraint = order_b.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+    )]
+    pub order_b: Account<'info, LimitOrder>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_MATCH_ORDERS))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("match_orders")]
+#[derive(Accounts)]
+pub struct MatchOrdersCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_MATCH_ORDERS))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = order_a.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_a: Account<'info, LimitOrder>,
+    #[account(
+        mut,
+        constraint = order_b.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_b: Account<'info, LimitOrder>,
+}
+
+#[init_computation_definition_accounts("match_orders", payer)]
+#[derive(Accounts)]
+pub struct InitMatchOrdersCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("batch_auction", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct BatchAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_0.owner.as_ref(), order_0.order_id.to_le_bytes().as_ref()],
+        bump = order_0.bump,
+        constraint = order_0.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_0.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+    )]
+    pub order_0: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_1.owner.as_ref(), order_1.order_id.to_le_bytes().as_ref()],
+        bump = order_1.bump,
+        constraint = order_1.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_1.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+        constraint = order_1.base_mint == order_0.base_mint @ ErrorCode::MintMismatch,
+        constraint = order_1.quote_mint == order_0.quote_mint @ ErrorCode::MintMismatch,
+    )]
+    pub order_1: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_2.owner.as_ref(), order_2.order_id.to_le_bytes().as_ref()],
+        bump = order_2.bump,
+        constraint = order_2.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_2.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+        constraint = order_2.base_mint == order_0.base_mint @ ErrorCode::MintMismatch,
+        constraint = order_2.quote_mint == order_0.quote_mint @ ErrorCode::MintMismatch,
+    )]
+    pub order_2: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order_3.owner.as_ref(), order_3.order_id.to_le_bytes().as_ref()],
+        bump = order_3.bump,
+        constraint = order_3.is_initialized @ ErrorCode::OrderNotInitialized,
+        constraint = order_3.matcher == payer.key() @ ErrorCode::NotOrderMatcher,
+        constraint = order_3.base_mint == order_0.base_mint @ ErrorCode::MintMismatch,
+        constraint = order_3.quote_mint == order_0.quote_mint @ ErrorCode::MintMismatch,
+    )]
+    pub order_3: Box<Account<'info, LimitOrder>>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+impl<'info> BatchAuction<'info> {
+    /// Orders in slot order
+    fn orders(&self) -> [&LimitOrder; AUCTION_SIZE] {
+        [&self.order_0, &self.order_1, &self.order_2, &self.order_3]
+    }
+
+    /// Orders in slot order, for locking
+    fn orders_mut(&mut self) -> [&mut LimitOrder; AUCTION_SIZE] {
+        [
+            &mut self.order_0,
+            &mut self.order_1,
+            &mut self.order_2,
+            &mut self.order_3,
+        ]
+    }
+
+    /// Order addresses in slot order
+    fn order_keys(&self) -> [Pubkey; AUCTION_SIZE] {
+        order_keys(&self.order_0, &self.order_1, &self.order_2, &self.order_3)
+    }
+}
+
+#[callback_accounts("batch_auction")]
+#[derive(Accounts)]
+pub struct BatchAuctionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BATCH_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = order_0.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_0: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        constraint = order_1.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_1: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        constraint = order_2.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_2: Box<Account<'info, LimitOrder>>,
+    #[account(
+        mut,
+        constraint = order_3.is_locked_by(&computation_request) @ ErrorCode::OrderNotLocked,
+    )]
+    pub order_3: Box<Account<'info, LimitOrder>>,
+}
+
+impl<'info> BatchAuctionCallback<'info> {
+    /// Orders in slot order
+    fn orders(&self) -> [&LimitOrder; AUCTION_SIZE] {
+        [&self.order_0, &self.order_1, &self.order_2, &self.order_3]
+    }
+
+    /// Orders in slot order, for writing
+    fn orders_mut(&mut self) -> [&mut LimitOrder; AUCTION_SIZE] {
+        [
+            &mut self.order_0,
+            &mut self.order_1,
+            &mut self.order_2,
+            &mut self.order_3,
+        ]
+    }
+
+    /// Order addresses in slot order
+    fn order_keys(&self) -> [Pubkey; AUCTION_SIZE] {
+        order_keys(&self.order_0, &self.order_1, &self.order_2, &self.order_3)
+    }
 }
 
-// =============================================================================
-// ACCOUNT STRUCTURES
-// =============================================================================
+#[init_computation_definition_accounts("batch_auction", payer)]
+#[derive(Accounts)]
+pub struct InitBatchAuctionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
 
-// Private Transfer Accounts
-#[queue_computation_accounts("private_transfer", payer)]
+// Sealed Auction Accounts
+#[queue_computation_accounts("init_auction", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, auction_id: u64)]
+pub struct CreateAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub lot_mint: Account<'info, Mint>,
+    #[account(constraint = bid_mint.key() != lot_mint.key() @ ErrorCode::AuctionMintsMatch)]
+    pub bid_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SealedAuction::INIT_SPACE,
+        seeds = [AUCTION_SEED, payer.key().as_ref(), auction_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, lot_mint.key().as_ref(), payer.key().as_ref()],
+        bump = seller_lot_balance.bump,
+        constraint = seller_lot_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub seller_lot_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("init_auction")]
+#[derive(Accounts)]
+pub struct InitAuctionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = auction.is_locked_by(&computation_request) @ ErrorCode::AuctionNotLocked,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        constraint = seller_lot_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub seller_lot_balance: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the auction rent if the lot is not escrowed
+    #[account(mut, address = auction.seller)]
+    pub seller: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("init_auction", payer)]
+#[derive(Accounts)]
+pub struct InitInitAuctionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("submit_bid", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SubmitBid<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.auction_id.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        constraint = auction.is_initialized @ ErrorCode::AuctionNotInitialized,
+        constraint = !auction.resolved @ ErrorCode::AuctionResolved,
+        constraint = auction.bidders.len() < MAX_AUCTION_BIDS @ ErrorCode::AuctionFull,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SealedBid::INIT_SPACE,
+        seeds = [BID_SEED, auction.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub bid: Account<'info, SealedBid>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, auction.bid_mint.as_ref(), payer.key().as_ref()],
+        bump = bidder_balance.bump,
+        constraint = bidder_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub bidder_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_BID))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("submit_bid")]
+#[derive(Accounts)]
+pub struct SubmitBidCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_BID))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = auction.is_locked_by(&computation_request) @ ErrorCode::AuctionNotLocked,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        constraint = bid.is_locked_by(&computation_request) @ ErrorCode::BidNotLocked,
+    )]
+    pub bid: Account<'info, SealedBid>,
+    #[account(
+        mut,
+        constraint = bidder_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub bidder_balance: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the bid rent if the computation aborts
+    #[account(mut, address = bid.bidder)]
+    pub bidder: SystemAccount<'info>,
+}
+
+#[init_computation_definition_accounts("submit_bid", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitBidCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("resolve_auction", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ResolveAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.auction_id.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        constraint = auction.is_initialized @ ErrorCode::AuctionNotInitialized,
+        constraint = !auction.resolved @ ErrorCode::AuctionResolved,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(mut, address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("resolve_auction")]
+#[derive(Accounts)]
+pub struct ResolveAuctionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RESOLVE_AUCTION))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = auction.is_locked_by(&computation_request) @ ErrorCode::AuctionNotLocked,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+}
+
+#[init_computation_definition_accounts("resolve_auction", payer)]
+#[derive(Accounts)]
+pub struct InitResolveAuctionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("settle_bid", bidder)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct PrivateTransfer<'info> {
+pub struct SettleBid<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub bidder: Signer<'info>,
+    #[account(
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.auction_id.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        constraint = auction.resolved @ ErrorCode::AuctionNotResolved,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        seeds = [BID_SEED, auction.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        constraint = bid.is_initialized @ ErrorCode::BidNotInitialized,
+    )]
+    pub bid: Account<'info, SealedBid>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, auction.bid_mint.as_ref(), bidder.key().as_ref()],
+        bump = bid_balance.bump,
+        constraint = bid_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub bid_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, auction.lot_mint.as_ref(), bidder.key().as_ref()],
+        bump = lot_balance.bump,
+        constraint = lot_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub lot_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
     #[account(
         init_if_needed,
         space = 9,
-        payer = payer,
+        payer = bidder,
         seeds = [&SIGN_PDA_SEED],
         bump,
         address = derive_sign_pda!(),
@@ -287,7 +8033,7 @@ pub struct PrivateTransfer<'info> {
     #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
     /// CHECK: computation_account
     pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRANSFER))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_BID))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
@@ -299,28 +8045,59 @@ pub struct PrivateTransfer<'info> {
     pub arcium_program: Program<'info, Arcium>,
 }
 
-#[callback_accounts("private_transfer")]
+#[callback_accounts("settle_bid")]
 #[derive(Accounts)]
-pub struct PrivateTransferCallback<'info> {
+pub struct SettleBidCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_PRIVATE_TRANSFER))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_BID))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
-    /// CHECK: computation_account
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
     pub computation_account: UncheckedAccount<'info>,
     #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar
     pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = bid.is_locked_by(&computation_request) @ ErrorCode::BidNotLocked,
+    )]
+    pub bid: Account<'info, SealedBid>,
+    #[account(
+        mut,
+        constraint = bid_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub bid_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = lot_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub lot_balance: Account<'info, EncryptedBalanceAccount>,
+    /// Receives the bid rent once the escrow is paid out
+    #[account(mut, address = bid.bidder)]
+    pub bidder: SystemAccount<'info>,
 }
 
-#[init_computation_definition_accounts("private_transfer", payer)]
+#[init_computation_definition_accounts("settle_bid", payer)]
 #[derive(Accounts)]
-pub struct InitPrivateTransferCompDef<'info> {
+pub struct InitSettleBidCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     #[account(mut, address = derive_mxe_pda!())]
     pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
@@ -330,17 +8107,46 @@ pub struct InitPrivateTransferCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// Check Balance Accounts
-#[queue_computation_accounts("check_balance", payer)]
+#[queue_computation_accounts("settle_auction", seller)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct CheckBalance<'info> {
+pub struct SettleAuction<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, seller.key().as_ref(), auction.auction_id.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        constraint = auction.resolved @ ErrorCode::AuctionNotResolved,
+        constraint = !auction.seller_settled @ ErrorCode::AuctionSettled,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, auction.bid_mint.as_ref(), seller.key().as_ref()],
+        bump = bid_balance.bump,
+        constraint = bid_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub bid_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, auction.lot_mint.as_ref(), seller.key().as_ref()],
+        bump = lot_balance.bump,
+        constraint = lot_balance.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub lot_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
     #[account(
         init_if_needed,
         space = 9,
-        payer = payer,
+        payer = seller,
         seeds = [&SIGN_PDA_SEED],
         bump,
         address = derive_sign_pda!(),
@@ -357,7 +8163,7 @@ pub struct CheckBalance<'info> {
     #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
     /// CHECK: computation_account
     pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCE))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_AUCTION))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
@@ -369,28 +8175,56 @@ pub struct CheckBalance<'info> {
     pub arcium_program: Program<'info, Arcium>,
 }
 
-#[callback_accounts("check_balance")]
+#[callback_accounts("settle_auction")]
 #[derive(Accounts)]
-pub struct CheckBalanceCallback<'info> {
+pub struct SettleAuctionCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCE))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SETTLE_AUCTION))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
-    /// CHECK: computation_account
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
     pub computation_account: UncheckedAccount<'info>,
     #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar
     pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = auction.is_locked_by(&computation_request) @ ErrorCode::AuctionNotLocked,
+    )]
+    pub auction: Account<'info, SealedAuction>,
+    #[account(
+        mut,
+        constraint = bid_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub bid_balance: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = lot_balance.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub lot_balance: Account<'info, EncryptedBalanceAccount>,
 }
 
-#[init_computation_definition_accounts("check_balance", payer)]
+#[init_computation_definition_accounts("settle_auction", payer)]
 #[derive(Accounts)]
-pub struct InitCheckBalanceCompDef<'info> {
+pub struct InitSettleAuctionCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     #[account(mut, address = derive_mxe_pda!())]
     pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
@@ -400,13 +8234,42 @@ pub struct InitCheckBalanceCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// Validate Swap Accounts
-#[queue_computation_accounts("validate_swap", payer)]
+// Disclosure Accounts
+#[queue_computation_accounts("disclose_balance", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct ValidateSwap<'info> {
+#[instruction(computation_offset: u64, disclosure_id: u64)]
+pub struct DiscloseBalance<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BalanceDisclosure::INIT_SPACE,
+        seeds = [DISCLOSURE_SEED, payer.key().as_ref(), disclosure_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub disclosure: Account<'info, BalanceDisclosure>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComputationRequest::INIT_SPACE,
+        seeds = [COMPUTATION_REQUEST_SEED, computation_offset.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub computation_request: Account<'info, ComputationRequest>,
     #[account(
         init_if_needed,
         space = 9,
@@ -427,7 +8290,7 @@ pub struct ValidateSwap<'info> {
     #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet))]
     /// CHECK: computation_account
     pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_SWAP))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DISCLOSE_BALANCE))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(mut, address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
@@ -439,28 +8302,54 @@ pub struct ValidateSwap<'info> {
     pub arcium_program: Program<'info, Arcium>,
 }
 
-#[callback_accounts("validate_swap")]
+#[callback_accounts("disclose_balance")]
 #[derive(Accounts)]
-pub struct ValidateSwapCallback<'info> {
+pub struct DiscloseBalanceCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_SWAP))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DISCLOSE_BALANCE))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
-    /// CHECK: computation_account
+    #[account(address = derive_comp_pda!(
+        computation_request.computation_offset,
+        mxe_account,
+        ErrorCode::ClusterNotSet
+    ))]
+    /// CHECK: computation_account, the one `computation_request` was queued for
     pub computation_account: UncheckedAccount<'info>,
     #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar
     pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    #[account(
+        mut,
+        constraint = balance_account.is_locked_by(&computation_request) @ ErrorCode::BalanceNotLocked,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+    #[account(
+        mut,
+        constraint = disclosure.is_locked_by(&computation_request) @ ErrorCode::DisclosureNotLocked,
+    )]
+    pub disclosure: Account<'info, BalanceDisclosure>,
+    /// Receives the disclosure rent if the computation aborts
+    #[account(mut, address = disclosure.owner)]
+    pub owner: SystemAccount<'info>,
 }
 
-#[init_computation_definition_accounts("validate_swap", payer)]
+#[init_computation_definition_accounts("disclose_balance", payer)]
 #[derive(Accounts)]
-pub struct InitValidateSwapCompDef<'info> {
+pub struct InitDiscloseBalanceCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     #[account(mut, address = derive_mxe_pda!())]
     pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
@@ -470,20 +8359,177 @@ pub struct InitValidateSwapCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
+// Reclaim Accounts
+#[derive(Accounts)]
+pub struct ReclaimExpiredComputation<'info> {
+    pub payer: Signer<'info>,
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut)]
+    pub computation_request: Account<'info, ComputationRequest>,
+    /// Receives the rent of accounts closed by the reclaim
+    #[account(mut, address = computation_request.requester @ ErrorCode::Unauthorized)]
+    pub requester: SystemAccount<'info>,
+    /// Refund accounts, required only when the request holds an escrowed deposit
+    #[account(address = computation_request.escrow_mint @ ErrorCode::MintMismatch)]
+    pub mint: Option<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, computation_request.escrow_mint.as_ref()],
+        bump,
+    )]
+    pub vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = refund_token_account.mint == computation_request.escrow_mint @ ErrorCode::MintMismatch,
+        constraint = refund_token_account.owner == requester.key() @ ErrorCode::Unauthorized,
+    )]
+    pub refund_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+// Viewing Key Accounts
+#[derive(Accounts)]
+pub struct SetViewingKey<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, balance_account.mint.as_ref(), payer.key().as_ref()],
+        bump = balance_account.bump,
+        constraint = balance_account.is_initialized @ ErrorCode::BalanceNotInitialized,
+    )]
+    pub balance_account: Account<'info, EncryptedBalanceAccount>,
+}
+
 // =============================================================================
 // EVENTS
 // =============================================================================
 
+#[event]
+pub struct AdminTransferredEvent {
+    /// Admin before the transfer
+    pub previous_admin: Pubkey,
+    /// Admin that accepted the proposal
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct PauseUpdatedEvent {
+    /// Whether queueing is now paused
+    pub paused: bool,
+}
+
+#[event]
+pub struct ComputationTimeoutUpdatedEvent {
+    /// Slots after queueing before a computation may be reclaimed
+    pub timeout_slots: u64,
+}
+
+#[event]
+pub struct BalanceCreatedEvent {
+    /// Owner of the new encrypted balance account
+    pub owner: Pubkey,
+    /// Mint the balance is denominated in
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct DepositEvent {
+    /// Owner of the credited balance account
+    pub owner: Pubkey,
+    /// Deposited mint
+    pub mint: Pubkey,
+    /// Deposited amount (public, it left a token account)
+    pub amount: u64,
+}
+
+#[event]
+pub struct WithdrawEvent {
+    /// Owner of the debited balance account
+    pub owner: Pubkey,
+    /// Withdrawn mint
+    pub mint: Pubkey,
+    /// Token account the tokens were released to
+    pub destination: Pubkey,
+    /// Amount released (zero if the balance was insufficient)
+    pub amount: u64,
+}
+
+#[event]
+pub struct ComputationAbortedEvent {
+    /// Wallet that queued the computation
+    pub requester: Pubkey,
+    /// Circuit the computation ran
+    pub circuit: CircuitKind,
+    /// Arcium computation offset
+    pub computation_offset: u64,
+}
+
+#[event]
+pub struct ComputationExpiredEvent {
+    /// Wallet that queued the computation
+    pub requester: Pubkey,
+    /// Circuit the computation ran
+    pub circuit: CircuitKind,
+    /// Arcium computation offset
+    pub computation_offset: u64,
+    /// Escrowed tokens returned to the requester
+    pub refunded: u64,
+}
+
 #[event]
 pub struct PrivateTransferEvent {
     /// Encrypted boolean - was transfer valid?
     pub is_valid: [u8; 32],
     /// Encrypted u64 - new sender balance
     pub new_sender_balance: [u8; 32],
+    /// Encrypted u8 - 0 ok, 1 insufficient funds, 2 overflow, 3 recipient mismatch,
+    /// 4 stale sequence
+    pub error_code: [u8; 32],
+    /// Encrypted u64 - protocol fee charged (zero if rejected)
+    pub fee: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct TransferReceivedEvent {
+    /// Owner of the credited balance account
+    pub recipient: Pubkey,
+    /// Encrypted u64 - amount credited (zero if rejected)
+    pub amount: [u8; 32],
+    /// Encrypted u64 - recipient's unclaimed incoming balance
+    pub new_incoming: [u8; 32],
+    /// Nonce for decryption (with the recipient's key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct BatchTransferEvent {
+    /// Owners of the credited balance accounts, in slot order (unused slots
+    /// are credited zero)
+    pub recipients: [Pubkey; BATCH_SIZE],
+    /// Encrypted boolean - was the whole batch applied?
+    pub is_valid: [u8; 32],
+    /// Encrypted u64 - new sender balance
+    pub new_sender_balance: [u8; 32],
+    /// Encrypted u8 - same codes as `PrivateTransferEvent::error_code`
+    pub error_code: [u8; 32],
+    /// Encrypted u64 - protocol fee charged across all slots (zero if rejected)
+    pub fee: [u8; 32],
     /// Nonce for decryption
     pub nonce: [u8; 16],
 }
 
+#[event]
+pub struct PriceUpdatedEvent {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// New price, scaled by `PRICE_SCALE`
+    pub price: u64,
+    pub slot: u64,
+}
+
 #[event]
 pub struct BalanceCheckEvent {
     /// Encrypted boolean - does balance meet minimum?
@@ -496,22 +8542,423 @@ pub struct BalanceCheckEvent {
 pub struct SwapValidationEvent {
     /// Encrypted boolean - is swap valid?
     pub is_valid: [u8; 32],
-    /// Encrypted u64 - new input balance
+    /// Encrypted u64 - new input token balance
     pub new_input_balance: [u8; 32],
     /// Encrypted boolean - is slippage acceptable?
     pub slippage_ok: [u8; 32],
+    /// Encrypted u64 - new output token balance
+    pub new_output_balance: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct OracleSwapValidationEvent {
+    /// Encrypted boolean - is swap valid?
+    pub is_valid: [u8; 32],
+    /// Encrypted boolean - does the oracle output meet min_output?
+    pub slippage_ok: [u8; 32],
+    /// Encrypted u64 - output implied by the oracle price
+    pub expected_output: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct PoolCreatedEvent {
+    pub pool: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct LiquidityAddedEvent {
+    pub pool: Pubkey,
+    /// Encrypted boolean - were both amounts added?
+    pub is_valid: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct LiquidityRemovedEvent {
+    pub pool: Pubkey,
+    /// Encrypted boolean - were both amounts removed?
+    pub is_valid: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct CpmmSwapEvent {
+    pub pool: Pubkey,
+    /// Encrypted boolean - was the swap applied?
+    pub is_valid: [u8; 32],
+    /// Encrypted u64 - amount of the output mint received
+    pub amount_out: [u8; 32],
+    /// Encrypted u64 - fee kept by the pool, in the input mint
+    pub fee: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct OrderPlacedEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    /// Encrypted bool - whether the balance covered the order's escrow
+    pub is_valid: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct OrderSettledEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    /// Whether the proceeds were credited; false only if a balance would overflow
+    pub settled: bool,
+    /// Whether the escrow was refunded and the order closed
+    pub closed: bool,
+}
+
+#[event]
+pub struct OrdersMatchedEvent {
+    pub order_a: Pubkey,
+    pub order_b: Pubkey,
+    /// Encrypted bool - whether the orders crossed, readable only by the matcher
+    pub matched: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct OrderFilledEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    /// Encrypted u64 - base units filled by this match
+    pub filled: [u8; 32],
+    /// Encrypted u64 - size still unfilled
+    pub remaining: [u8; 32],
+    /// Encrypted u64 - proceeds waiting to be settled, in the mint being received
+    pub proceeds: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct AuctionClearedEvent {
+    /// Orders submitted to the batch, in slot order
+    pub orders: [Pubkey; AUCTION_SIZE],
+    /// Encrypted bool - whether any volume crossed, readable only by the matcher
+    pub cleared: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct AuctionFillEvent {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    /// Encrypted u64 - base units filled by this auction
+    pub filled: [u8; 32],
+    /// Encrypted u64 - uniform clearing price, scaled by `PRICE_SCALE`
+    pub clearing_price: [u8; 32],
+    /// Encrypted u64 - size still unfilled
+    pub remaining: [u8; 32],
+    /// Encrypted u64 - proceeds waiting to be settled, in the mint being received
+    pub proceeds: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct SealedAuctionCreatedEvent {
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    pub lot_mint: Pubkey,
+    pub bid_mint: Pubkey,
+    pub second_price: bool,
+    /// Amount of `lot_mint` escrowed from the seller
+    pub lot_amount: u64,
+    /// First slot at which bids are refused
+    pub end_slot: u64,
+    /// Lowest clearing price in `bid_mint`
+    pub reserve_price: u64,
+}
+
+#[event]
+pub struct SealedBidSubmittedEvent {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    /// Position of the bid in submission order
+    pub bid_index: u8,
+    /// Encrypted bool - whether the bidder's balance covered the bid
+    pub is_valid: [u8; 32],
+    /// Nonce for decryption
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct SealedAuctionResolvedEvent {
+    pub auction: Pubkey,
+    /// Winning bidder, `None` if no positive bid was placed
+    pub winner: Option<Pubkey>,
+    /// Index of the winning bid, `NO_BID` if none
+    pub winner_index: u8,
+    /// Price the winner pays in the auction's `bid_mint`
+    pub clearing_price: u64,
+}
+
+#[event]
+pub struct SealedBidSettledEvent {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    /// Whether the escrow was paid out and the bid closed
+    pub settled: bool,
+}
+
+#[event]
+pub struct SealedAuctionSettledEvent {
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    /// Whether a bid won, so the seller was paid rather than refunded the lot
+    pub sold: bool,
+    /// Whether the payout was credited; false only if a balance would overflow
+    pub settled: bool,
+}
+
+#[event]
+pub struct BalanceDisclosedEvent {
+    pub disclosure: Pubkey,
+    pub owner: Pubkey,
+    pub balance_account: Pubkey,
+    pub auditor: Pubkey,
+    /// Balance sequence the disclosed value was read at
+    pub sequence: u64,
+    /// Encrypted u64 - balance, encrypted to the auditor's key
+    pub encrypted_balance: [u8; 32],
     /// Nonce for decryption
     pub nonce: [u8; 16],
 }
 
+#[event]
+pub struct ViewingKeyUpdatedEvent {
+    pub balance_account: Pubkey,
+    pub owner: Pubkey,
+    /// Newly registered viewing key, `None` if removed
+    pub viewing_key: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct TransferSentViewEvent {
+    /// Sender's balance account
+    pub balance_account: Pubkey,
+    /// Owner of the credited balance account
+    pub recipient: Pubkey,
+    /// Sender balance sequence after the transfer
+    pub sequence: u64,
+    /// Encrypted u64 - amount sent (zero if rejected)
+    pub amount: [u8; 32],
+    /// Encrypted u64 - protocol fee charged (zero if rejected)
+    pub fee: [u8; 32],
+    /// Encrypted u64 - new sender balance
+    pub new_sender_balance: [u8; 32],
+    /// Nonce for decryption (with the sender's viewing key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct BatchTransferSentViewEvent {
+    /// Sender's balance account
+    pub balance_account: Pubkey,
+    /// Owners of the credited balance accounts, in slot order
+    pub recipients: [Pubkey; BATCH_SIZE],
+    /// Sender balance sequence after the batch
+    pub sequence: u64,
+    /// Encrypted u64 - amount sent per slot (all zero if rejected)
+    pub amounts: [[u8; 32]; BATCH_SIZE],
+    /// Encrypted u64 - protocol fee charged across all slots (zero if rejected)
+    pub fee: [u8; 32],
+    /// Encrypted u64 - new sender balance
+    pub new_sender_balance: [u8; 32],
+    /// Nonce for decryption (with the sender's viewing key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct TransferReceivedViewEvent {
+    /// Recipient's balance account
+    pub balance_account: Pubkey,
+    /// Owner of the debited balance account
+    pub sender: Pubkey,
+    /// Encrypted u64 - amount credited (zero if rejected)
+    pub amount: [u8; 32],
+    /// Encrypted u64 - recipient's unclaimed incoming balance
+    pub new_incoming: [u8; 32],
+    /// Nonce for decryption (with the recipient's viewing key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct IncomingClaimedEvent {
+    /// Owner of the balance account
+    pub owner: Pubkey,
+    /// Mint the balance is denominated in
+    pub mint: Pubkey,
+    /// Balance sequence after the claim
+    pub sequence: u64,
+    /// Encrypted u64 - amount moved into the spendable balance
+    pub claimed: [u8; 32],
+    /// Encrypted u64 - new spendable balance
+    pub new_balance: [u8; 32],
+    /// Nonce for decryption (with the owner's key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct IncomingClaimedViewEvent {
+    /// Claiming balance account
+    pub balance_account: Pubkey,
+    /// Balance sequence after the claim
+    pub sequence: u64,
+    /// Encrypted u64 - amount moved into the spendable balance
+    pub claimed: [u8; 32],
+    /// Encrypted u64 - new spendable balance
+    pub new_balance: [u8; 32],
+    /// Nonce for decryption (with the viewing key)
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct FeeSweptEvent {
+    /// Treasury authority whose balance was credited
+    pub authority: Pubkey,
+    /// Mint the fee is denominated in
+    pub mint: Pubkey,
+    /// `FeeRecord` that was swept
+    pub fee_record: Pubkey,
+    /// Authority balance sequence after the sweep
+    pub sequence: u64,
+    /// Whether the fee was credited and the record closed
+    pub swept: bool,
+}
+
 // =============================================================================
 // ERRORS
 // =============================================================================
 
 #[error_code]
 pub enum ErrorCode {
+    /// No longer returned: callbacks record an abort on the `ComputationRequest`
+    /// instead of failing. Kept so the error codes of later variants don't shift.
     #[msg("The computation was aborted")]
     AbortedComputation,
+    #[msg("Computation output failed signature verification")]
+    SignatureVerificationFailed,
     #[msg("Cluster not set")]
     ClusterNotSet,
+    #[msg("Encrypted balance account has not been initialized by the MXE")]
+    BalanceNotInitialized,
+    #[msg("Sender and recipient balance accounts must differ")]
+    SelfTransfer,
+    #[msg("Balance accounts are for different mints")]
+    MintMismatch,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Computation request is not queued")]
+    ComputationNotQueued,
+    #[msg("Balance has a pending computation")]
+    BalanceLocked,
+    #[msg("Computation has not reached its timeout")]
+    ComputationNotExpired,
+    #[msg("Balance is not locked by this computation")]
+    BalanceNotLocked,
+    #[msg("Refund accounts are required to reclaim an escrowed deposit")]
+    MissingRefundAccounts,
+    #[msg("Signer is not authorized")]
+    Unauthorized,
+    #[msg("Fee exceeds MAX_FEE_BPS")]
+    FeeTooHigh,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Batch recipient balance accounts must be distinct")]
+    DuplicateRecipient,
+    #[msg("Swap input and output balances must be for different mints")]
+    SwapMintsMatch,
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Price feed has not been updated recently")]
+    StalePrice,
+    #[msg("Pool mints must differ and mint_a must sort before mint_b")]
+    InvalidPoolMints,
+    #[msg("Pool reserves have not been initialized by the MXE")]
+    PoolNotInitialized,
+    #[msg("Pool has a pending computation")]
+    PoolLocked,
+    #[msg("Pool is not locked by this computation")]
+    PoolNotLocked,
+    #[msg("Order base and quote mints must differ")]
+    OrderMintsMatch,
+    #[msg("Order has not been initialized by the MXE")]
+    OrderNotInitialized,
+    #[msg("Order has a pending computation")]
+    OrderLocked,
+    #[msg("Order is not locked by this computation")]
+    OrderNotLocked,
+    #[msg("An order cannot be matched against itself")]
+    SelfMatch,
+    #[msg("Batch auction orders must be distinct")]
+    DuplicateOrder,
+    #[msg("Auction lot and bid mints must differ")]
+    AuctionMintsMatch,
+    #[msg("Auction has not been initialized by the MXE")]
+    AuctionNotInitialized,
+    #[msg("Auction has a pending computation")]
+    AuctionLocked,
+    #[msg("Auction is not locked by this computation")]
+    AuctionNotLocked,
+    #[msg("Auction has already been resolved")]
+    AuctionResolved,
+    #[msg("Auction has reached MAX_AUCTION_BIDS")]
+    AuctionFull,
+    #[msg("Disclosure is not locked by this computation")]
+    DisclosureNotLocked,
+    #[msg("Incoming balance has a pending computation")]
+    IncomingLocked,
+    #[msg("Incoming balance is not locked by this computation")]
+    IncomingNotLocked,
+    #[msg("Remaining accounts must be the computation's locked accounts, in order")]
+    LockedAccountsMismatch,
+    #[msg("A fee record must be passed exactly when the mint's treasury charges a fee")]
+    FeeRecordMismatch,
+    #[msg("Fee record has not been written by the MXE")]
+    FeeRecordNotInitialized,
+    #[msg("Fee record has a pending computation")]
+    FeeRecordLocked,
+    #[msg("Fee record is not locked by this computation")]
+    FeeRecordNotLocked,
+    #[msg("Payer is not the matcher every order named")]
+    NotOrderMatcher,
+    #[msg("Auction end slot has already passed")]
+    AuctionEndPassed,
+    #[msg("Auction has ended and accepts no more bids")]
+    AuctionEnded,
+    #[msg("Auction has not reached its end slot")]
+    AuctionNotEnded,
+    #[msg("Auction has not been resolved")]
+    AuctionNotResolved,
+    #[msg("Auction proceeds have already been settled")]
+    AuctionSettled,
+    #[msg("Bid has not been written by the MXE")]
+    BidNotInitialized,
+    #[msg("Bid has a pending computation")]
+    BidLocked,
+    #[msg("Bid is not locked by this computation")]
+    BidNotLocked,
+    #[msg("Computation timeout is below the minimum")]
+    ComputationTimeoutTooShort,
 }
@@ -54,11 +54,56 @@ async function main() {
   const mxeAccount = getMXEAccAddress(program.programId)
   console.log("MXE Account:", mxeAccount.toBase58())
 
+  // Create the program config; the upgrade authority running this becomes admin
+  const [configAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  )
+  if (await connection.getAccountInfo(configAccount)) {
+    console.log("Config:", configAccount.toBase58(), "(already initialized)")
+  } else {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    )
+    const tx = await (program.methods as any)
+      .initializeConfig()
+      .accounts({
+        payer: wallet.publicKey,
+        config: configAccount,
+        program: program.programId,
+        programData,
+      })
+      .rpc()
+    console.log("Config:", configAccount.toBase58(), "initialized:", tx)
+  }
+
   // Initialize computation definitions
   const compDefs = [
+    "create_balance",
+    "deposit",
+    "withdraw",
+    "claim_incoming",
     "private_transfer",
+    "batch_transfer",
+    "sweep_fee",
     "check_balance",
     "validate_swap",
+    "validate_oracle_swap",
+    "init_pool",
+    "add_liquidity",
+    "remove_liquidity",
+    "swap_cpmm",
+    "place_order",
+    "match_orders",
+    "settle_order",
+    "batch_auction",
+    "init_auction",
+    "submit_bid",
+    "resolve_auction",
+    "settle_bid",
+    "settle_auction",
+    "disclose_balance",
   ]
 
   for (const name of compDefs) {
@@ -84,6 +129,7 @@ async function main() {
       const tx = await (program.methods as any)[methodName]()
         .accounts({
           payer: wallet.publicKey,
+          config: configAccount,
           mxeAccount,
           compDefAccount,
         })